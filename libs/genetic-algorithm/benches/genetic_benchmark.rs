@@ -1,15 +1,29 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use lib_genetic_algorithm::{CrossoverMethod, GaussianMutation, MutationMethod, UniformCrossover};
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use lib_genetic_algorithm::{
+    CrossoverMethod, MutationMethod, NormalMutation, UniformCrossover, UniformMutation,
+};
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 
-fn guassian_mutation_benchmark(c: &mut Criterion) {
-    let guassian_mutation = GaussianMutation::new(0.5, 0.5);
+fn uniform_mutation_benchmark(c: &mut Criterion) {
+    let uniform_mutation = UniformMutation::new(0.5, 0.5);
+    let mut child = vec![1.0, 2.0, 3.0, 4.0, 5.0].into_iter().collect();
+    let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+    c.bench_function("uniform mutation", |b| {
+        b.iter(|| uniform_mutation.mutate(black_box(&mut rng), black_box(&mut child)))
+    });
+}
+
+fn normal_mutation_benchmark(c: &mut Criterion) {
+    let normal_mutation = NormalMutation::new(0.5, 0.5);
     let mut child = vec![1.0, 2.0, 3.0, 4.0, 5.0].into_iter().collect();
     let mut rng = ChaCha8Rng::from_seed(Default::default());
 
-    c.bench_function("guassian mutation", |b| {
-        b.iter(|| guassian_mutation.mutate(black_box(&mut rng), black_box(&mut child)))
+    c.bench_function("normal mutation", |b| {
+        b.iter(|| normal_mutation.mutate(black_box(&mut rng), black_box(&mut child)))
     });
 }
 
@@ -32,7 +46,8 @@ fn uniform_crossover_benchmark(c: &mut Criterion) {
 
 criterion_group!(
     benches,
-    guassian_mutation_benchmark,
+    uniform_mutation_benchmark,
+    normal_mutation_benchmark,
     uniform_crossover_benchmark,
 );
 criterion_main!(benches);
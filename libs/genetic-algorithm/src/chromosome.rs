@@ -1,8 +1,14 @@
 use crate::*;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chromosome {
     genes: Vec<f32>,
+
+    /// Per-gene mutation step sizes, used by self-adaptive mutation (see
+    /// [`SelfAdaptiveMutation`]); empty unless that mutation is in use.
+    #[cfg_attr(feature = "serde", serde(default))]
+    step_sizes: Vec<f32>,
 }
 
 impl Chromosome {
@@ -21,6 +27,16 @@ impl Chromosome {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
         self.genes.iter_mut()
     }
+
+    pub fn step_sizes(&self) -> &[f32] {
+        &self.step_sizes
+    }
+
+    pub fn set_step_sizes(&mut self, step_sizes: Vec<f32>) {
+        assert!(step_sizes.is_empty() || step_sizes.len() == self.len());
+
+        self.step_sizes = step_sizes;
+    }
 }
 
 impl Index<usize> for Chromosome {
@@ -35,6 +51,7 @@ impl FromIterator<f32> for Chromosome {
     fn from_iter<T: IntoIterator<Item = f32>>(iter: T) -> Self {
         Self {
             genes: iter.into_iter().collect(),
+            step_sizes: Vec::new(),
         }
     }
 }
@@ -42,7 +59,7 @@ impl FromIterator<f32> for Chromosome {
 impl IntoIterator for Chromosome {
     type Item = f32;
     type IntoIter = std::vec::IntoIter<f32>;
-    
+
     fn into_iter(self) -> Self::IntoIter {
         self.genes.into_iter()
     }
@@ -52,6 +69,7 @@ impl IntoIterator for Chromosome {
 impl PartialEq for Chromosome {
     fn eq(&self, other: &Self) -> bool {
         approx::relative_eq!(self.genes.as_slice(), other.genes.as_slice())
+            && approx::relative_eq!(self.step_sizes.as_slice(), other.step_sizes.as_slice())
     }
 }
 
@@ -60,9 +78,7 @@ mod tests {
     use super::*;
 
     fn chromosome() -> Chromosome {
-        Chromosome {
-            genes: vec![3.0, 1.0, 2.0],
-        }
+        vec![3.0, 1.0, 2.0].into_iter().collect()
     }
 
     mod len {
@@ -133,6 +149,7 @@ mod tests {
         fn test() {
             let chromosome = Chromosome {
                 genes: vec![3.0, 1.0, 2.0],
+                step_sizes: Vec::new(),
             };
 
             let genes: Vec<_> = chromosome.into_iter().collect();
@@ -144,18 +161,92 @@ mod tests {
         }
     }
 
+    mod step_sizes {
+        use super::*;
+
+        #[test]
+        fn are_empty_by_default() {
+            assert!(chromosome().step_sizes().is_empty());
+        }
+
+        #[test]
+        fn can_be_set() {
+            let mut chromosome = chromosome();
+            chromosome.set_step_sizes(vec![0.1, 0.2, 0.3]);
+
+            approx::assert_relative_eq!(chromosome.step_sizes(), [0.1, 0.2, 0.3].as_ref());
+        }
+
+        #[test]
+        #[should_panic]
+        fn must_match_genes() {
+            chromosome().set_step_sizes(vec![0.1]);
+        }
+    }
+
     mod index {
         use super::*;
 
         #[test]
         fn test() {
-            let chromesome = Chromosome {
-                genes: vec![3.0, 1.0, 2.0],
-            };
+            let chromesome = chromosome();
 
             approx::assert_relative_eq!(chromesome[0], 3.0);
             approx::assert_relative_eq!(chromesome[1], 1.0);
             approx::assert_relative_eq!(chromesome[2], 2.0);
         }
     }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+
+        fn chromosome() -> Chromosome {
+            let mut chromosome = super::chromosome();
+            chromosome.set_step_sizes(vec![0.1, 0.2, 0.3]);
+            chromosome
+        }
+
+        #[test]
+        fn json() {
+            let json = serde_json::to_string(&chromosome()).unwrap();
+
+            assert_eq!(
+                json,
+                r#"{"genes":[3.0,1.0,2.0],"step_sizes":[0.1,0.2,0.3]}"#
+            );
+            assert_eq!(
+                serde_json::from_str::<Chromosome>(&json).unwrap(),
+                chromosome()
+            );
+        }
+
+        #[test]
+        fn json_without_step_sizes() {
+            let actual: Chromosome = serde_json::from_str(r#"{"genes":[3.0,1.0,2.0]}"#).unwrap();
+
+            assert_eq!(actual, super::chromosome());
+        }
+
+        #[test]
+        fn binary() {
+            let bytes = bincode::serialize(&chromosome()).unwrap();
+
+            assert_eq!(
+                bincode::deserialize::<Chromosome>(&bytes).unwrap(),
+                chromosome()
+            );
+        }
+
+        #[test]
+        fn population() {
+            let population = vec![chromosome(), super::chromosome()];
+            let bytes = bincode::serialize(&population).unwrap();
+
+            assert_eq!(
+                bincode::deserialize::<Vec<Chromosome>>(&bytes).unwrap(),
+                population
+            );
+        }
+    }
 }
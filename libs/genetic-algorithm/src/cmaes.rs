@@ -0,0 +1,290 @@
+use nalgebra::{DMatrix, DVector, SymmetricEigen};
+use rand_distr::{Distribution, StandardNormal};
+
+use crate::*;
+
+/// CMA-ES (Covariance Matrix Adaptation Evolution Strategy) - an
+/// alternative to [`GeneticAlgorithm`] for real-valued chromosomes.
+///
+/// Instead of recombining individuals, CMA-ES maintains a multivariate
+/// normal distribution (its mean, step size and covariance matrix) that
+/// it samples new individuals from; on each generation the distribution
+/// moves towards the fittest samples and stretches along the directions
+/// that have been paying off.
+///
+/// It's used the same way as [`GeneticAlgorithm`]: evaluate the population,
+/// hand it over to [`Cmaes::evolve()`] and get a new population to
+/// evaluate. The first population (which hasn't been sampled by CMA-ES) is
+/// used to initialize the distribution; the population size is given by
+/// the number of individuals passed on each call.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmaes {
+    /// Initial step size (standard deviation of samples).
+    sigma: f32,
+
+    state: Option<State>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct State {
+    mean: DVector<f64>,
+    sigma: f64,
+    covariance: DMatrix<f64>,
+
+    /// Evolution paths, for the covariance matrix and step size
+    pc: DVector<f64>,
+    ps: DVector<f64>,
+
+    /// Eigendecomposition of the covariance matrix: `B * D^2 * B^T`
+    b: DMatrix<f64>,
+    d: DVector<f64>,
+
+    generation: usize,
+}
+
+impl Cmaes {
+    pub fn new(sigma: f32) -> Self {
+        assert!(sigma > 0.0);
+
+        Self { sigma, state: None }
+    }
+
+    /// Returns the current mean of the distribution, i.e. CMA-ES' best
+    /// guess so far; `None` before the first generation.
+    pub fn mean(&self) -> Option<Chromosome> {
+        self.state
+            .as_ref()
+            .map(|state| state.mean.iter().map(|&gene| gene as f32).collect())
+    }
+
+    /// Returns the current step size; `None` before the first generation.
+    pub fn sigma(&self) -> Option<f32> {
+        self.state.as_ref().map(|state| state.sigma as f32)
+    }
+
+    pub fn evolve<I>(&mut self, rng: &mut dyn Rng, population: &[I]) -> (Vec<I>, Statistics)
+    where
+        I: Individual,
+    {
+        assert!(population.len() >= 2);
+
+        let genes = |individual: &I| -> DVector<f64> {
+            DVector::from_iterator(
+                individual.chromosome().len(),
+                individual.chromosome().iter().map(|&gene| gene as f64),
+            )
+        };
+
+        let state = self.state.get_or_insert_with(|| {
+            let n = population[0].chromosome().len();
+
+            let mean = population
+                .iter()
+                .map(genes)
+                .fold(DVector::zeros(n), |sum, genes| sum + genes)
+                / population.len() as f64;
+
+            State {
+                mean,
+                sigma: self.sigma as f64,
+                covariance: DMatrix::identity(n, n),
+                pc: DVector::zeros(n),
+                ps: DVector::zeros(n),
+                b: DMatrix::identity(n, n),
+                d: DVector::from_element(n, 1.0),
+                generation: 0,
+            }
+        });
+
+        state.tell(
+            population
+                .iter()
+                .map(|individual| (genes(individual), individual.fitness())),
+        );
+
+        let new_population = (0..population.len())
+            .map(|_| I::create(state.ask(rng)))
+            .collect();
+
+        (new_population, Statistics::new(population))
+    }
+}
+
+impl State {
+    /// Samples a new individual from the distribution.
+    fn ask(&self, rng: &mut dyn Rng) -> Chromosome {
+        let z = DVector::from_fn(self.mean.len(), |_, _| {
+            let z: f64 = StandardNormal.sample(rng);
+            z
+        });
+
+        let x = &self.mean + self.sigma * (&self.b * self.d.component_mul(&z));
+
+        x.iter().map(|&gene| gene as f32).collect()
+    }
+
+    /// Updates the distribution, given samples together with their fitness.
+    fn tell(&mut self, samples: impl Iterator<Item = (DVector<f64>, f32)>) {
+        let mut samples: Vec<_> = samples.collect();
+        samples.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        let n = self.mean.len() as f64;
+        let lambda = samples.len();
+        let mu = lambda / 2;
+
+        // Recombination weights - fitter samples weigh more
+        let weights: Vec<f64> = (1..=mu)
+            .map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln())
+            .collect();
+
+        let weights_sum: f64 = weights.iter().sum();
+        let weights: Vec<f64> = weights.iter().map(|w| w / weights_sum).collect();
+        let mu_eff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        // Learning rates
+        let cc = (4.0 + mu_eff / n) / (n + 4.0 + 2.0 * mu_eff / n);
+        let cs = (mu_eff + 2.0) / (n + mu_eff + 5.0);
+        let c1 = 2.0 / ((n + 1.3).powi(2) + mu_eff);
+        let cmu =
+            (1.0 - c1).min(2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / ((n + 2.0).powi(2) + mu_eff));
+        let damps = 1.0 + 2.0 * (((mu_eff - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        // Move the mean
+        let ys: Vec<_> = samples[..mu]
+            .iter()
+            .map(|(x, _)| (x - &self.mean) / self.sigma)
+            .collect();
+
+        let y_w = ys
+            .iter()
+            .zip(&weights)
+            .fold(DVector::zeros(self.mean.len()), |sum, (y, w)| sum + y * *w);
+
+        self.mean += self.sigma * &y_w;
+        self.generation += 1;
+
+        // Update evolution paths
+        let inv_sqrt_c =
+            &self.b * DMatrix::from_diagonal(&self.d.map(|d| 1.0 / d)) * self.b.transpose();
+
+        self.ps = (1.0 - cs) * &self.ps + (cs * (2.0 - cs) * mu_eff).sqrt() * (inv_sqrt_c * &y_w);
+
+        let ps_norm = self.ps.norm() / (1.0 - (1.0 - cs).powi(2 * self.generation as i32)).sqrt();
+        let hsig = if ps_norm / chi_n < 1.4 + 2.0 / (n + 1.0) {
+            1.0
+        } else {
+            0.0
+        };
+
+        self.pc = (1.0 - cc) * &self.pc + hsig * (cc * (2.0 - cc) * mu_eff).sqrt() * &y_w;
+
+        // Adapt the covariance matrix
+        let rank_one =
+            &self.pc * self.pc.transpose() + (1.0 - hsig) * cc * (2.0 - cc) * &self.covariance;
+
+        let rank_mu = ys.iter().zip(&weights).fold(
+            DMatrix::zeros(self.mean.len(), self.mean.len()),
+            |sum, (y, w)| sum + *w * (y * y.transpose()),
+        );
+
+        self.covariance = (1.0 - c1 - cmu) * &self.covariance + c1 * rank_one + cmu * rank_mu;
+
+        // Adapt the step size
+        self.sigma *= ((cs / damps) * (self.ps.norm() / chi_n - 1.0)).exp();
+
+        // Decompose the covariance matrix, for sampling
+        let covariance = (&self.covariance + self.covariance.transpose()) / 2.0;
+        let eigen = SymmetricEigen::new(covariance);
+
+        self.b = eigen.eigenvectors;
+        self.d = eigen.eigenvalues.map(|value| value.max(1e-20).sqrt());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Individual whose fitness is the (negated) distance from its genes to
+    /// the point of (1.0, 2.0, 3.0, ...).
+    struct SphereIndividual {
+        chromosome: Chromosome,
+        fitness: f32,
+    }
+
+    impl Individual for SphereIndividual {
+        fn create(chromosome: Chromosome) -> Self {
+            let fitness = -chromosome
+                .iter()
+                .enumerate()
+                .map(|(idx, gene)| (gene - (idx + 1) as f32).powi(2))
+                .sum::<f32>();
+
+            Self {
+                chromosome,
+                fitness,
+            }
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.chromosome
+        }
+
+        fn fitness(&self) -> f32 {
+            self.fitness
+        }
+    }
+
+    #[test]
+    fn converges_on_the_optimum() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut cmaes = Cmaes::new(0.5);
+
+        let mut population: Vec<_> = (0..12)
+            .map(|_| {
+                SphereIndividual::create((0..5).map(|_| rng.random_range(-1.0..=1.0)).collect())
+            })
+            .collect();
+
+        assert!(cmaes.mean().is_none());
+
+        for _ in 0..150 {
+            population = cmaes.evolve(&mut rng, &population).0;
+        }
+
+        let mean: Vec<_> = cmaes.mean().unwrap().into_iter().collect();
+
+        approx::assert_relative_eq!(
+            mean.as_slice(),
+            [1.0, 2.0, 3.0, 4.0, 5.0].as_ref(),
+            epsilon = 1e-3
+        );
+
+        assert!(cmaes.sigma().unwrap() < 0.01);
+    }
+
+    #[test]
+    fn keeps_the_population_size() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut cmaes = Cmaes::new(0.5);
+
+        let population: Vec<_> = (0..7)
+            .map(|_| {
+                SphereIndividual::create((0..3).map(|_| rng.random_range(-1.0..=1.0)).collect())
+            })
+            .collect();
+
+        let (new_population, stats) = cmaes.evolve(&mut rng, &population);
+
+        assert_eq!(new_population.len(), 7);
+        assert!(new_population
+            .iter()
+            .all(|individual| individual.chromosome().len() == 3));
+        assert!(stats.max_fitness() <= 0.0);
+    }
+}
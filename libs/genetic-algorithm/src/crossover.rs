@@ -1,7 +1,12 @@
-pub use uniform::*;
+pub use self::{arithmetic::*, blend::*, block::*, k_point::*, simulated_binary::*, uniform::*};
 
 use crate::*;
 
+mod arithmetic;
+mod blend;
+mod block;
+mod k_point;
+mod simulated_binary;
 mod uniform;
 
 pub trait CrossoverMethod {
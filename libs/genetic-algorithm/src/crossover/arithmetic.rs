@@ -0,0 +1,63 @@
+use crate::*;
+
+/// Whole arithmetic crossover: `child = alpha * parent_a + (1 - alpha) * parent_b`,
+/// gene by gene.
+#[derive(Clone, Debug)]
+pub struct ArithmeticCrossover {
+    /// How much of `parent_a` the child gets:
+    /// - 0.5 = child is right in between its parents
+    /// - 1.0 = child is a copy of `parent_a`
+    alpha: f32,
+}
+
+impl ArithmeticCrossover {
+    pub fn new(alpha: f32) -> Self {
+        assert!((0.0..=1.0).contains(&alpha));
+
+        Self { alpha }
+    }
+}
+
+impl Default for ArithmeticCrossover {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl CrossoverMethod for ArithmeticCrossover {
+    fn crossover(
+        &self,
+        _rng: &mut dyn Rng,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| self.alpha * a + (1.0 - self.alpha) * b)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0, -3.0].into_iter().collect();
+        let parent_b: Chromosome = vec![5.0, 2.0, 3.0].into_iter().collect();
+
+        let child: Vec<_> = ArithmeticCrossover::new(0.25)
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect();
+
+        approx::assert_relative_eq!(child.as_slice(), [4.0, 2.0, 1.5].as_ref());
+    }
+}
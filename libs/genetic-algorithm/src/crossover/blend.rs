@@ -0,0 +1,89 @@
+use crate::*;
+
+/// BLX-α blend crossover: each gene is drawn uniformly from the range
+/// spanned by both parents' genes, extended by `alpha` times its width on
+/// both sides.
+///
+/// E.g. for genes 1.0 and 3.0 and `alpha` = 0.5, the child's gene is drawn
+/// from `0.0..=4.0`.
+#[derive(Clone, Debug)]
+pub struct BlendCrossover {
+    /// How far outside of its parents' range the child's gene can land:
+    /// - 0.0 = always between the parents
+    /// - 0.5 = the usual choice, keeps the population's spread stable
+    alpha: f32,
+}
+
+impl BlendCrossover {
+    pub fn new(alpha: f32) -> Self {
+        assert!(alpha >= 0.0);
+
+        Self { alpha }
+    }
+}
+
+impl Default for BlendCrossover {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl CrossoverMethod for BlendCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn Rng,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| {
+                let extent = self.alpha * (a - b).abs();
+                let min = a.min(b) - extent;
+                let max = a.max(b) + extent;
+
+                min + (max - min) * rng.random::<f32>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0, -3.0, 0.0].into_iter().collect();
+        let parent_b: Chromosome = vec![3.0, 2.0, 3.0, 0.5].into_iter().collect();
+
+        let child: Vec<_> = BlendCrossover::new(0.5)
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect();
+
+        approx::assert_relative_eq!(
+            child.as_slice(),
+            [0.7489624, 2.0, 4.9087563, 0.3814245].as_ref()
+        );
+    }
+
+    #[test]
+    fn without_alpha_stays_between_the_parents() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = (1..=100).map(|n| n as f32).collect();
+        let parent_b: Chromosome = (1..=100).map(|n| -n as f32).collect();
+
+        let child = BlendCrossover::new(0.0).crossover(&mut rng, &parent_a, &parent_b);
+
+        for (gene, a) in child.iter().zip(parent_a.iter()) {
+            assert!((-a..=*a).contains(gene));
+        }
+    }
+}
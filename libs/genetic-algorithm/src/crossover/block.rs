@@ -0,0 +1,92 @@
+use std::ops::Range;
+
+use crate::*;
+
+/// Like [`UniformCrossover`], but instead of picking single genes, it picks
+/// whole blocks of them - e.g. all the genes that make up one neuron.
+///
+/// Useful when genes within a block only make sense together; mixing them
+/// gene by gene would destroy whatever the block has learned.
+#[derive(Clone, Debug)]
+pub struct BlockCrossover {
+    blocks: Vec<Range<usize>>,
+}
+
+impl BlockCrossover {
+    /// Creates the crossover from consecutive, non-overlapping blocks that
+    /// cover the entire chromosome, e.g. `[0..4, 4..8, 8..11]`.
+    pub fn new(blocks: Vec<Range<usize>>) -> Self {
+        assert!(!blocks.is_empty());
+        assert_eq!(blocks[0].start, 0);
+
+        assert!(blocks
+            .windows(2)
+            .all(|blocks| blocks[0].end == blocks[1].start));
+
+        Self { blocks }
+    }
+}
+
+impl CrossoverMethod for BlockCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn Rng,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+        assert_eq!(self.blocks.last().unwrap().end, parent_a.len());
+
+        self.blocks
+            .iter()
+            .flat_map(|block| {
+                let parent = if rng.random_bool(0.5) {
+                    parent_a
+                } else {
+                    parent_b
+                };
+
+                block.clone().map(move |idx| parent[idx])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = (1..=10).map(|n| n as f32).collect();
+        let parent_b: Chromosome = (1..=10).map(|n| -n as f32).collect();
+
+        let child: Vec<_> = BlockCrossover::new(vec![0..4, 4..8, 8..10])
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect();
+
+        approx::assert_relative_eq!(
+            child.as_slice(),
+            [-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, 9.0, 10.0].as_ref()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_gaps_between_blocks() {
+        BlockCrossover::new(vec![0..4, 5..8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_blocks_not_matching_the_chromosome() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent: Chromosome = (1..=10).map(|n| n as f32).collect();
+
+        BlockCrossover::new(vec![0..4, 4..8]).crossover(&mut rng, &parent, &parent);
+    }
+}
@@ -0,0 +1,125 @@
+use rand::seq::index;
+
+use crate::*;
+
+/// Cuts both parents at `k` random points and builds the child from
+/// alternating segments: first one from `parent_a`, second one from
+/// `parent_b`, third one from `parent_a` again and so on.
+///
+/// Contrary to [`UniformCrossover`], neighbouring genes tend to be
+/// inherited together.
+#[derive(Clone, Debug)]
+pub struct KPointCrossover {
+    k: usize,
+}
+
+impl KPointCrossover {
+    pub fn new(k: usize) -> Self {
+        assert!(k > 0);
+
+        Self { k }
+    }
+}
+
+impl CrossoverMethod for KPointCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn Rng,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        if parent_a.len() < 2 {
+            return parent_a.clone();
+        }
+
+        // Cut points are between genes, i.e. in `1..len`
+        let k = self.k.min(parent_a.len() - 1);
+        let mut cuts = index::sample(rng, parent_a.len() - 1, k).into_vec();
+        cuts.sort_unstable();
+
+        let mut cuts = cuts.into_iter().map(|cut| cut + 1).peekable();
+        let mut from_a = true;
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .enumerate()
+            .map(|(idx, (&a, &b))| {
+                if cuts.next_if_eq(&idx).is_some() {
+                    from_a = !from_a;
+                }
+
+                if from_a {
+                    a
+                } else {
+                    b
+                }
+            })
+            .collect()
+    }
+}
+
+/// [`KPointCrossover`] with just one cut point.
+#[derive(Clone, Debug, Default)]
+pub struct SinglePointCrossover;
+
+impl CrossoverMethod for SinglePointCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn Rng,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        KPointCrossover::new(1).crossover(rng, parent_a, parent_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(method: &dyn CrossoverMethod) -> Vec<f32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = (1..=10).map(|n| n as f32).collect();
+        let parent_b: Chromosome = (1..=10).map(|n| -n as f32).collect();
+
+        method
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn single_point() {
+        let actual = actual(&SinglePointCrossover);
+        let expected = vec![1.0, 2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0, -10.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn k_point() {
+        let actual = actual(&KPointCrossover::new(3));
+        let expected = vec![1.0, 2.0, -3.0, -4.0, -5.0, -6.0, -7.0, 8.0, 9.0, -10.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn with_more_points_than_genes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+        let parent_b: Chromosome = vec![-1.0, -2.0, -3.0].into_iter().collect();
+
+        let child: Vec<_> = KPointCrossover::new(10)
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect();
+
+        approx::assert_relative_eq!(child.as_slice(), [1.0, -2.0, 3.0].as_ref());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::*;
+
+/// Simulated binary crossover (SBX) - mimics, for real-valued genes, the
+/// way single-point crossover spreads children around their parents when
+/// working on binary strings.
+///
+/// Each gene ends up close to one of its parents' genes, with `eta`
+/// controlling how close.
+#[derive(Clone, Debug)]
+pub struct SimulatedBinaryCrossover {
+    /// Distribution index:
+    /// - small values (e.g. 2.0) = children can land far from their parents
+    /// - large values (e.g. 20.0) = children stay close to their parents
+    eta: f32,
+}
+
+impl SimulatedBinaryCrossover {
+    pub fn new(eta: f32) -> Self {
+        assert!(eta >= 0.0);
+
+        Self { eta }
+    }
+}
+
+impl Default for SimulatedBinaryCrossover {
+    fn default() -> Self {
+        Self::new(15.0)
+    }
+}
+
+impl CrossoverMethod for SimulatedBinaryCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn Rng,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| {
+                let u = rng.random::<f32>();
+
+                let beta = if u <= 0.5 {
+                    (2.0 * u).powf(1.0 / (self.eta + 1.0))
+                } else {
+                    (1.0 / (2.0 * (1.0 - u))).powf(1.0 / (self.eta + 1.0))
+                };
+
+                // SBX yields two children; we only need one of them, so
+                // let's pick one at random
+                let sign = if rng.random_bool(0.5) { -1.0 } else { 1.0 };
+
+                0.5 * ((a + b) + sign * beta * (a - b))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0, 2.0, -3.0, 0.0].into_iter().collect();
+        let parent_b: Chromosome = vec![3.0, 2.0, 3.0, 0.5].into_iter().collect();
+
+        let child: Vec<_> = SimulatedBinaryCrossover::new(2.0)
+            .crossover(&mut rng, &parent_a, &parent_b)
+            .into_iter()
+            .collect();
+
+        approx::assert_relative_eq!(
+            child.as_slice(),
+            [1.279208, 2.0, 3.0727615, 0.44989628].as_ref()
+        );
+    }
+
+    #[test]
+    fn keeps_the_parents_mean() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = vec![1.0; 1000].into_iter().collect();
+        let parent_b: Chromosome = vec![3.0; 1000].into_iter().collect();
+
+        let child = SimulatedBinaryCrossover::new(2.0).crossover(&mut rng, &parent_a, &parent_b);
+        let mean = child.iter().sum::<f32>() / (child.len() as f32);
+
+        approx::assert_relative_eq!(mean, 2.0, epsilon = 0.1);
+    }
+}
@@ -0,0 +1,69 @@
+/// How many of the fittest individuals get copied, unchanged, into the
+/// next generation.
+///
+/// Without elitism the best individual of a generation can easily get
+/// lost - nothing guarantees that it will be picked as a parent, and even
+/// if it is, its child won't be an exact copy of it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Elitism {
+    /// Keeps exactly this many individuals
+    Count(usize),
+
+    /// Keeps this fraction of the population (rounded down):
+    /// - 0.0 = no individuals are kept
+    /// - 1.0 = all individuals are kept (i.e. nothing evolves)
+    Fraction(f32),
+}
+
+impl Elitism {
+    /// Returns how many individuals of a population of given size are
+    /// elites.
+    pub fn count(&self, population_size: usize) -> usize {
+        let count = match *self {
+            Self::Count(count) => count,
+            Self::Fraction(fraction) => {
+                assert!((0.0..=1.0).contains(&fraction));
+
+                ((population_size as f32) * fraction) as usize
+            }
+        };
+
+        count.min(population_size)
+    }
+}
+
+impl Default for Elitism {
+    fn default() -> Self {
+        Self::Count(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count() {
+        assert_eq!(Elitism::Count(3).count(10), 3);
+        assert_eq!(Elitism::Count(30).count(10), 10);
+    }
+
+    #[test]
+    fn fraction() {
+        assert_eq!(Elitism::Fraction(0.0).count(10), 0);
+        assert_eq!(Elitism::Fraction(0.25).count(10), 2);
+        assert_eq!(Elitism::Fraction(1.0).count(10), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fraction_out_of_range() {
+        Elitism::Fraction(1.5).count(10);
+    }
+
+    #[test]
+    fn default_keeps_no_one() {
+        assert_eq!(Elitism::default().count(10), 0);
+    }
+}
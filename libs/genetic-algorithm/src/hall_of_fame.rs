@@ -0,0 +1,193 @@
+use crate::*;
+
+/// Bounded archive of the best chromosomes ever seen, so that they don't get
+/// lost when the population drifts away from them.
+///
+/// Chromosomes closer than `min_distance` (see [`compatibility_distance()`])
+/// to one already in the hall of fame count as duplicates - only the fitter
+/// one of them is kept.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HallOfFame {
+    /// How many entries are kept at most.
+    capacity: usize,
+
+    min_distance: f32,
+
+    /// Sorted by fitness, descending.
+    entries: Vec<HallOfFameEntry>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HallOfFameEntry {
+    pub chromosome: Chromosome,
+    pub fitness: f32,
+
+    /// Generation the chromosome was seen in.
+    pub generation: usize,
+}
+
+impl HallOfFame {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            min_distance: 0.0,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn with_min_distance(mut self, min_distance: f32) -> Self {
+        assert!(min_distance >= 0.0);
+
+        self.min_distance = min_distance;
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns entries, the fittest first.
+    pub fn entries(&self) -> &[HallOfFameEntry] {
+        &self.entries
+    }
+
+    /// Returns the fittest entry ever seen.
+    pub fn champion(&self) -> Option<&HallOfFameEntry> {
+        self.entries.first()
+    }
+
+    /// Considers given population, seen in given generation, for the hall
+    /// of fame.
+    pub fn record<I>(&mut self, population: &[I], generation: usize)
+    where
+        I: Individual,
+    {
+        for individual in population {
+            self.consider(individual.chromosome(), individual.fitness(), generation);
+        }
+    }
+
+    fn consider(&mut self, chromosome: &Chromosome, fitness: f32, generation: usize) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.len() == self.capacity
+            && self
+                .entries
+                .last()
+                .is_some_and(|worst| worst.fitness >= fitness)
+        {
+            return;
+        }
+
+        let duplicate = self.entries.iter().position(|entry| {
+            compatibility_distance(&entry.chromosome, chromosome) <= self.min_distance
+        });
+
+        if let Some(duplicate) = duplicate {
+            if self.entries[duplicate].fitness >= fitness {
+                return;
+            }
+
+            self.entries.remove(duplicate);
+        }
+
+        let idx = self
+            .entries
+            .partition_point(|entry| entry.fitness >= fitness);
+
+        self.entries.insert(
+            idx,
+            HallOfFameEntry {
+                chromosome: chromosome.clone(),
+                fitness,
+                generation,
+            },
+        );
+
+        self.entries.truncate(self.capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    fn fitness(hall_of_fame: &HallOfFame) -> Vec<f32> {
+        hall_of_fame
+            .entries()
+            .iter()
+            .map(|entry| entry.fitness)
+            .collect()
+    }
+
+    #[test]
+    fn keeps_the_best_ever_seen() {
+        let mut hall_of_fame = HallOfFame::new(3);
+
+        hall_of_fame.record(&[individual(&[1.0, 1.0]), individual(&[5.0, 0.0])], 0);
+
+        hall_of_fame.record(
+            &[
+                individual(&[0.0, 0.0]),
+                individual(&[2.0, 2.0]),
+                individual(&[0.0, 3.0]),
+            ],
+            1,
+        );
+
+        assert_eq!(fitness(&hall_of_fame), [5.0, 4.0, 3.0]);
+
+        let champion = hall_of_fame.champion().unwrap();
+
+        assert_eq!(champion.generation, 0);
+        assert_eq!(
+            champion.chromosome,
+            individual(&[5.0, 0.0]).chromosome().clone()
+        );
+    }
+
+    #[test]
+    fn skips_duplicates() {
+        let mut hall_of_fame = HallOfFame::new(3).with_min_distance(0.5);
+
+        hall_of_fame.record(
+            &[
+                individual(&[1.0, 1.0]),
+                individual(&[1.0, 1.0]),
+                individual(&[1.2, 1.2]),
+                individual(&[3.0, 0.0]),
+            ],
+            0,
+        );
+
+        // [1.0, 1.0] got superseded by its fitter near-duplicate
+        assert_eq!(fitness(&hall_of_fame), [3.0, 2.4]);
+        assert_eq!(hall_of_fame.entries()[1].generation, 0);
+    }
+
+    #[test]
+    fn empty() {
+        let mut hall_of_fame = HallOfFame::new(0);
+
+        hall_of_fame.record(&[individual(&[1.0])], 0);
+
+        assert!(hall_of_fame.is_empty());
+        assert!(hall_of_fame.champion().is_none());
+    }
+}
@@ -4,6 +4,12 @@ pub trait Individual {
     fn create(chromosome: Chromosome) -> Self;
     fn chromosome(&self) -> &Chromosome;
     fn fitness(&self) -> f32;
+
+    /// How long the individual has been around (in whatever unit suits the
+    /// problem); used by [`Replacement::OldestOut`].
+    fn age(&self) -> usize {
+        0
+    }
 }
 
 #[cfg(test)]
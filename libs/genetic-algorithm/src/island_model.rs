@@ -0,0 +1,199 @@
+pub use self::migration::*;
+
+use crate::*;
+
+mod migration;
+
+/// Several genetic algorithms ("islands"), each evolving its own
+/// sub-population, with the fittest individuals periodically migrating
+/// between them.
+///
+/// Since islands evolve mostly in isolation, they tend to converge on
+/// different solutions - which keeps the overall population diverse for
+/// longer than a single, large population would.
+pub struct IslandModel<S> {
+    islands: Vec<GeneticAlgorithm<S>>,
+    migration: Migration,
+}
+
+impl<S> IslandModel<S>
+where
+    S: SelectionMethod,
+{
+    pub fn new(islands: Vec<GeneticAlgorithm<S>>) -> Self {
+        assert!(!islands.is_empty());
+
+        Self {
+            islands,
+            migration: Migration::default(),
+        }
+    }
+
+    pub fn with_migration(mut self, migration: Migration) -> Self {
+        self.migration = migration;
+        self
+    }
+
+    pub fn islands(&self) -> &[GeneticAlgorithm<S>] {
+        &self.islands
+    }
+
+    pub fn islands_mut(&mut self) -> &mut [GeneticAlgorithm<S>] {
+        &mut self.islands
+    }
+
+    /// Evolves each of the populations on its own island, and then - if
+    /// migration is due in this `generation` - replaces the last offspring
+    /// on each island with copies of the fittest individuals coming from
+    /// other islands.
+    ///
+    /// Returns new populations together with statistics for each island.
+    pub fn evolve<I>(
+        &mut self,
+        rng: &mut dyn Rng,
+        generation: usize,
+        populations: &[Vec<I>],
+    ) -> (Vec<Vec<I>>, Vec<Statistics>)
+    where
+        I: Individual,
+    {
+        assert_eq!(populations.len(), self.islands.len());
+
+        let (mut new_populations, stats): (Vec<_>, Vec<_>) = self
+            .islands
+            .iter_mut()
+            .zip(populations)
+            .map(|(island, population)| island.evolve(rng, population))
+            .unzip();
+
+        if self.islands.len() > 1 && self.migration.is_due(generation) {
+            self.migrate(rng, populations, &mut new_populations);
+        }
+
+        (new_populations, stats)
+    }
+
+    fn migrate<I>(&self, rng: &mut dyn Rng, populations: &[Vec<I>], new_populations: &mut [Vec<I>])
+    where
+        I: Individual,
+    {
+        let mut immigrants = vec![Vec::new(); populations.len()];
+
+        for (from, population) in populations.iter().enumerate() {
+            // Fitness is known only for the old population, so that's
+            // where emigrants come from
+            let mut emigrants: Vec<_> = population.iter().collect();
+            emigrants.sort_by(|a, b| b.fitness().total_cmp(&a.fitness()));
+            emigrants.truncate(self.migration.migrants());
+
+            for to in self
+                .migration
+                .topology()
+                .destinations(rng, from, populations.len())
+            {
+                immigrants[to].extend(emigrants.iter().map(|emigrant| emigrant.chromosome()));
+            }
+        }
+
+        for (population, immigrants) in new_populations.iter_mut().zip(immigrants) {
+            for (individual, chromosome) in population.iter_mut().rev().zip(immigrants) {
+                *individual = I::create(chromosome.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    fn island() -> GeneticAlgorithm<RouletteWheelSelection> {
+        GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover,
+            UniformMutation::new(0.0, 0.0),
+        )
+    }
+
+    fn populations() -> Vec<Vec<TestIndividual>> {
+        vec![
+            vec![
+                individual(&[1.0, 1.0]), // fitness = 2.0
+                individual(&[4.0, 4.0]), // fitness = 8.0
+                individual(&[2.0, 2.0]), // fitness = 4.0
+            ],
+            vec![
+                individual(&[0.0, 0.0]),
+                individual(&[0.0, 0.0]),
+                individual(&[0.0, 0.0]),
+            ],
+        ]
+    }
+
+    #[test]
+    fn migrants_replace_the_last_offspring() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut model = IslandModel::new(vec![island(), island()]).with_migration(Migration::new(
+            MigrationTopology::Ring,
+            1,
+            1,
+        ));
+
+        let (new_populations, stats) = model.evolve(&mut rng, 1, &populations());
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].max_fitness(), 8.0);
+        assert_eq!(stats[1].max_fitness(), 0.0);
+
+        // Island #1 has got nothing but clones of its own individuals...
+        assert_eq!(new_populations[1][0], individual(&[0.0, 0.0]));
+        assert_eq!(new_populations[1][1], individual(&[0.0, 0.0]));
+
+        // ... except for the immigrant, which is the fittest of island #0
+        assert_eq!(new_populations[1][2], individual(&[4.0, 4.0]));
+
+        // ... and vice versa
+        assert_eq!(new_populations[0][2], individual(&[0.0, 0.0]));
+    }
+
+    #[test]
+    fn migration_happens_only_when_due() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut model = IslandModel::new(vec![island(), island()]).with_migration(Migration::new(
+            MigrationTopology::Ring,
+            5,
+            1,
+        ));
+
+        let (new_populations, _) = model.evolve(&mut rng, 4, &populations());
+
+        assert!(new_populations[1]
+            .iter()
+            .all(|individual| *individual == self::individual(&[0.0, 0.0])));
+    }
+
+    #[test]
+    fn single_island_evolves_like_a_single_genetic_algorithm() {
+        let mut model = IslandModel::new(vec![island()]);
+        let population = populations().remove(0);
+
+        let (actual, _) = model.evolve(
+            &mut ChaCha8Rng::from_seed(Default::default()),
+            10,
+            std::slice::from_ref(&population),
+        );
+
+        let (expected, _) =
+            island().evolve(&mut ChaCha8Rng::from_seed(Default::default()), &population);
+
+        assert_eq!(actual, vec![expected]);
+    }
+}
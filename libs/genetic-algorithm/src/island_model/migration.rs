@@ -0,0 +1,154 @@
+use crate::*;
+
+/// How (and how often) individuals move between islands of an
+/// [`IslandModel`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Migration {
+    topology: MigrationTopology,
+
+    /// Migration happens every this many generations.
+    interval: usize,
+
+    /// How many of the fittest individuals each island sends away on every
+    /// migration.
+    migrants: usize,
+}
+
+impl Migration {
+    pub fn new(topology: MigrationTopology, interval: usize, migrants: usize) -> Self {
+        assert!(interval > 0);
+
+        Self {
+            topology,
+            interval,
+            migrants,
+        }
+    }
+
+    pub fn topology(&self) -> MigrationTopology {
+        self.topology
+    }
+
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+
+    pub fn migrants(&self) -> usize {
+        self.migrants
+    }
+
+    /// Returns whether individuals should migrate after given generation.
+    pub fn is_due(&self, generation: usize) -> bool {
+        generation.is_multiple_of(self.interval)
+    }
+}
+
+impl Default for Migration {
+    fn default() -> Self {
+        Self::new(MigrationTopology::Ring, 10, 1)
+    }
+}
+
+/// Which islands send their migrants where.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MigrationTopology {
+    /// Each island sends migrants to the next one, with the last island
+    /// sending them to the first one.
+    Ring,
+
+    /// Each island sends migrants to all of the other islands.
+    FullyConnected,
+
+    /// Each island sends migrants to another island, picked at random on
+    /// each migration.
+    Random,
+}
+
+impl MigrationTopology {
+    /// Returns islands that island `from` sends its migrants to.
+    pub fn destinations(&self, rng: &mut dyn Rng, from: usize, islands: usize) -> Vec<usize> {
+        assert!(from < islands);
+
+        if islands < 2 {
+            return Vec::new();
+        }
+
+        match self {
+            Self::Ring => vec![(from + 1) % islands],
+            Self::FullyConnected => (0..islands).filter(|&to| to != from).collect(),
+            Self::Random => {
+                // Picks one of the *other* islands
+                let to = rng.random_range(0..islands - 1);
+
+                vec![if to >= from { to + 1 } else { to }]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn is_due() {
+        let migration = Migration::new(MigrationTopology::Ring, 3, 1);
+
+        let due: Vec<_> = (1..=9).filter(|&gen| migration.is_due(gen)).collect();
+
+        assert_eq!(due, vec![3, 6, 9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_interval() {
+        Migration::new(MigrationTopology::Ring, 0, 1);
+    }
+
+    mod destinations {
+        use super::*;
+
+        fn destinations(topology: MigrationTopology, from: usize, islands: usize) -> Vec<usize> {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            topology.destinations(&mut rng, from, islands)
+        }
+
+        #[test]
+        fn ring() {
+            assert_eq!(destinations(MigrationTopology::Ring, 0, 3), vec![1]);
+            assert_eq!(destinations(MigrationTopology::Ring, 2, 3), vec![0]);
+        }
+
+        #[test]
+        fn fully_connected() {
+            assert_eq!(
+                destinations(MigrationTopology::FullyConnected, 1, 4),
+                vec![0, 2, 3]
+            );
+        }
+
+        #[test]
+        fn random_never_picks_the_source_island() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            for _ in 0..100 {
+                let to = MigrationTopology::Random.destinations(&mut rng, 1, 3);
+
+                assert_eq!(to.len(), 1);
+                assert!(to[0] == 0 || to[0] == 2);
+            }
+        }
+
+        #[test]
+        fn single_island() {
+            assert!(destinations(MigrationTopology::Ring, 0, 1).is_empty());
+            assert!(destinations(MigrationTopology::FullyConnected, 0, 1).is_empty());
+            assert!(destinations(MigrationTopology::Random, 0, 1).is_empty());
+        }
+    }
+}
@@ -1,24 +1,39 @@
-#![feature(type_alias_impl_trait)]
-
 use std::{iter::FromIterator, ops::Index};
 
 pub use self::{
-    chromosome::*, crossover::*, individual::*, mutation::*, selection::*, statistics::*,
+    chromosome::*, cmaes::*, crossover::*, elitism::*, hall_of_fame::*, individual::*,
+    island_model::*, mutation::*, mutation_schedule::*, novelty_archive::*, nsga2::*,
+    replacement::*, reproduction::*, scaling::*, selection::*, speciation::*, statistics::*,
 };
 
 use rand::{seq::IndexedRandom, Rng, RngExt};
 
 mod chromosome;
+mod cmaes;
 mod crossover;
+mod elitism;
+mod hall_of_fame;
 mod individual;
+mod island_model;
 mod mutation;
+mod mutation_schedule;
+mod novelty_archive;
+mod nsga2;
+mod replacement;
+mod reproduction;
+mod scaling;
 mod selection;
+mod speciation;
 mod statistics;
 
 pub struct GeneticAlgorithm<S> {
     selection_method: S,
     crossover_method: Box<dyn CrossoverMethod>,
     mutation_method: Box<dyn MutationMethod>,
+    elitism: Elitism,
+    fitness_scaling: Box<dyn FitnessScaling>,
+    speciation: Option<Speciation>,
+    reproduction: Option<Box<dyn ReproductionStrategy>>,
 }
 
 impl<S> GeneticAlgorithm<S>
@@ -34,33 +49,267 @@ where
             selection_method,
             crossover_method: Box::new(crossover_method),
             mutation_method: Box::new(mutation_method),
+            elitism: Elitism::default(),
+            fitness_scaling: Box::new(NoScaling),
+            speciation: None,
+            reproduction: None,
+        }
+    }
+
+    pub fn with_elitism(mut self, elitism: Elitism) -> Self {
+        self.elitism = elitism;
+        self
+    }
+
+    pub fn with_fitness_scaling(mut self, fitness_scaling: impl FitnessScaling + 'static) -> Self {
+        self.fitness_scaling = Box::new(fitness_scaling);
+        self
+    }
+
+    pub fn with_speciation(mut self, speciation: Speciation) -> Self {
+        self.speciation = Some(speciation);
+        self
+    }
+
+    /// Makes offspring come from given strategy, instead of from the
+    /// selection method, crossover method and mutation method; fitness
+    /// scaling and speciation don't apply then.
+    pub fn with_reproduction(mut self, reproduction: impl ReproductionStrategy + 'static) -> Self {
+        self.reproduction = Some(Box::new(reproduction));
+        self
+    }
+
+    /// Returns state of the reproduction strategy, if any (see
+    /// [`ReproductionStrategy::state()`]).
+    pub fn reproduction_state(&self) -> Vec<(Chromosome, f32)> {
+        self.reproduction
+            .as_ref()
+            .map(|reproduction| reproduction.state())
+            .unwrap_or_default()
+    }
+
+    pub fn set_reproduction_state(&mut self, state: Vec<(Chromosome, f32)>) {
+        if let Some(reproduction) = &mut self.reproduction {
+            reproduction.set_state(state);
         }
     }
 
-    pub fn evolve<I>(&self, rng: &mut dyn Rng, population: &[I]) -> (Vec<I>, Statistics)
+    /// Replaces the mutation method, e.g. to follow a [`MutationSchedule`].
+    pub fn set_mutation_method(&mut self, mutation_method: impl MutationMethod + 'static) {
+        self.mutation_method = Box::new(mutation_method);
+    }
+
+    pub fn evolve<I>(&mut self, rng: &mut dyn Rng, population: &[I]) -> (Vec<I>, Statistics)
     where
         I: Individual,
     {
         assert!(!population.is_empty());
 
-        let new_population = (0..population.len())
-            .map(|_| {
-                let parent_a = self.selection_method.select(rng, population).chromosome();
+        let mut elites: Vec<_> = population.iter().collect();
+        elites.sort_by(|a, b| b.fitness().total_cmp(&a.fitness()));
+        elites.truncate(self.elitism.count(population.len()));
+
+        let offspring_count = population.len() - elites.len();
+        let mut stats = Statistics::new(population);
+
+        if let Some(reproduction) = self.reproduction.as_mut() {
+            let evaluated: Vec<_> = population
+                .iter()
+                .map(|individual| (individual.chromosome(), individual.fitness()))
+                .collect();
 
-                let parent_b = self.selection_method.select(rng, population).chromosome();
+            let offspring = reproduction.reproduce(rng, &evaluated, offspring_count);
 
-                let mut child = self.crossover_method.crossover(rng, parent_a, parent_b);
+            let new_population = elites
+                .into_iter()
+                .map(|elite| I::create(elite.chromosome().clone()))
+                .chain(offspring.into_iter().map(I::create))
+                .collect();
+
+            return (new_population, stats);
+        }
 
-                self.mutation_method.mutate(rng, &mut child);
+        let fitness: Vec<_> = population
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect();
 
-                I::create(child)
+        let mut scaled_population: Vec<_> = population
+            .iter()
+            .zip(self.fitness_scaling.scale(&fitness))
+            .map(|(individual, fitness)| Scaled {
+                individual,
+                fitness,
             })
             .collect();
 
-        let stats = Statistics::new(population);
+        let offspring: Vec<_> = if let Some(speciation) = &self.speciation {
+            let species = speciation.speciate(population);
+
+            // Fitness sharing
+            for members in &species {
+                for &idx in members {
+                    scaled_population[idx].fitness /= members.len() as f32;
+                }
+            }
+
+            let species_fitness: Vec<f32> = species
+                .iter()
+                .map(|members| {
+                    members
+                        .iter()
+                        .map(|&idx| scaled_population[idx].fitness)
+                        .sum()
+                })
+                .collect();
+
+            let species_sizes: Vec<_> = species.iter().map(|members| members.len()).collect();
+            let allotment = speciation.allot(&species_fitness, &species_sizes, offspring_count);
+
+            stats = stats.with_species_sizes(species_sizes);
+
+            species
+                .iter()
+                .zip(allotment)
+                .filter(|(_, count)| *count > 0)
+                .flat_map(|(members, count)| {
+                    let members: Vec<_> = members
+                        .iter()
+                        .map(|&idx| Scaled {
+                            individual: scaled_population[idx].individual,
+                            fitness: scaled_population[idx].fitness,
+                        })
+                        .collect();
+
+                    self.breed_many(rng, &members, count)
+                })
+                .collect()
+        } else {
+            self.breed_many(rng, &scaled_population, offspring_count)
+        };
+
+        let new_population = elites
+            .into_iter()
+            .map(|elite| I::create(elite.chromosome().clone()))
+            .chain(offspring.into_iter().map(I::create))
+            .collect();
 
         (new_population, stats)
     }
+
+    /// Produces a single offspring of the population, for steady-state
+    /// evolution - where offspring replace individuals one at a time (see
+    /// [`Replacement`]), instead of the entire population at once.
+    ///
+    /// Parents get selected as usual (with fitness scaling), but neither
+    /// speciation nor reproduction strategies apply here.
+    pub fn offspring<I>(&self, rng: &mut dyn Rng, population: &[I]) -> I
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let fitness: Vec<_> = population
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect();
+
+        let scaled_population: Vec<_> = population
+            .iter()
+            .zip(self.fitness_scaling.scale(&fitness))
+            .map(|(individual, fitness)| Scaled {
+                individual,
+                fitness,
+            })
+            .collect();
+
+        let child = self.breed_many(rng, &scaled_population, 1).remove(0);
+
+        I::create(child)
+    }
+
+    /// Breeds `count` children of given population; see
+    /// [`SelectionMethod::selects_many_at_once()`] for how parents get picked.
+    fn breed_many<I>(
+        &self,
+        rng: &mut dyn Rng,
+        population: &[Scaled<'_, I>],
+        count: usize,
+    ) -> Vec<Chromosome>
+    where
+        I: Individual,
+    {
+        if self.selection_method.selects_many_at_once() {
+            let parents = self
+                .selection_method
+                .select_many(rng, population, 2 * count);
+
+            parents
+                .chunks(2)
+                .map(|parents| self.breed(rng, parents[0].individual, parents[1].individual))
+                .collect()
+        } else {
+            (0..count)
+                .map(|_| {
+                    let parent_a = self.selection_method.select(rng, population).individual;
+                    let parent_b = self.selection_method.select(rng, population).individual;
+
+                    self.breed(rng, parent_a, parent_b)
+                })
+                .collect()
+        }
+    }
+
+    fn breed<I>(&self, rng: &mut dyn Rng, parent_a: &I, parent_b: &I) -> Chromosome
+    where
+        I: Individual,
+    {
+        let parent_a = parent_a.chromosome();
+
+        let parent_b = parent_b.chromosome();
+
+        let mut child = self.crossover_method.crossover(rng, parent_a, parent_b);
+
+        inherit_step_sizes(&mut child, parent_a, parent_b);
+
+        self.mutation_method.mutate(rng, &mut child);
+
+        child
+    }
+}
+
+/// Gives child the average of its parents' step sizes (the so-called
+/// intermediate recombination); crossover methods only ever look at genes.
+fn inherit_step_sizes(child: &mut Chromosome, parent_a: &Chromosome, parent_b: &Chromosome) {
+    let (a, b) = (parent_a.step_sizes(), parent_b.step_sizes());
+
+    if a.len() == child.len() && b.len() == child.len() {
+        child.set_step_sizes(a.iter().zip(b).map(|(a, b)| (a + b) / 2.0).collect());
+    }
+}
+
+/// Individual as seen by the selection method - with its fitness already
+/// scaled.
+struct Scaled<'a, I> {
+    individual: &'a I,
+    fitness: f32,
+}
+
+impl<I> Individual for Scaled<'_, I>
+where
+    I: Individual,
+{
+    fn create(_chromosome: Chromosome) -> Self {
+        unreachable!("selection methods don't create individuals")
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        self.individual.chromosome()
+    }
+
+    fn fitness(&self) -> f32 {
+        self.fitness
+    }
 }
 
 #[cfg(test)]
@@ -75,7 +324,7 @@ mod tests {
 
         let mut rng = ChaCha8Rng::from_seed(Default::default());
 
-        GaussianMutation::new(chance, coeff).mutate(&mut rng, &mut child);
+        UniformMutation::new(chance, coeff).mutate(&mut rng, &mut child);
         child.into_iter().collect()
     }
 
@@ -183,10 +432,10 @@ mod test {
     fn test() {
         let mut rng = ChaCha8Rng::from_seed(Default::default());
 
-        let ga = GeneticAlgorithm::new(
+        let mut ga = GeneticAlgorithm::new(
             RouletteWheelSelection::new(),
             UniformCrossover,
-            GaussianMutation::new(0.5, 0.5),
+            UniformMutation::new(0.5, 0.5),
         );
 
         let mut population = vec![
@@ -217,4 +466,175 @@ mod test {
 
         assert_eq!(population, expected_population);
     }
+
+    #[test]
+    fn elitism_keeps_the_fittest_individuals_unchanged() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover,
+            UniformMutation::new(1.0, 0.5),
+        )
+        .with_elitism(Elitism::Count(2));
+
+        let population = vec![
+            individual(&[0.0, 0.0, 0.0]), // fitness = 0.0
+            individual(&[1.0, 1.0, 1.0]), // fitness = 3.0
+            individual(&[1.0, 2.0, 1.0]), // fitness = 4.0
+            individual(&[1.0, 2.0, 4.0]), // fitness = 7.0
+        ];
+
+        let (new_population, _) = ga.evolve(&mut rng, &population);
+
+        assert_eq!(new_population.len(), population.len());
+        assert_eq!(new_population[0], individual(&[1.0, 2.0, 4.0]));
+        assert_eq!(new_population[1], individual(&[1.0, 2.0, 1.0]));
+        assert_ne!(new_population[2], new_population[0]);
+        assert_ne!(new_population[3], new_population[0]);
+    }
+
+    #[test]
+    fn selection_sees_scaled_fitness() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover,
+            UniformMutation::new(0.0, 0.0),
+        )
+        .with_fitness_scaling(ExponentialRanking::new(0.001));
+
+        let population = vec![
+            individual(&[0.0, 0.0, 0.0]), // fitness = 0.0
+            individual(&[1.0, 1.0, 1.0]), // fitness = 3.0
+            individual(&[1.0, 2.0, 1.0]), // fitness = 4.0
+            individual(&[1.0, 2.0, 4.0]), // fitness = 7.0
+        ];
+
+        let (new_population, stats) = ga.evolve(&mut rng, &population);
+
+        // With such a steep ranking, (almost) only the best individual gets
+        // picked, so (without mutations) it's the only one that remains
+        assert!(new_population
+            .iter()
+            .all(|individual| *individual == population[3]));
+
+        // ... but statistics still describe the raw fitness
+        assert_eq!(stats.max_fitness(), 7.0);
+        assert_eq!(stats.min_fitness(), 0.0);
+    }
+
+    #[test]
+    fn offspring_inherits_averaged_step_sizes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover,
+            UniformMutation::new(0.0, 0.0),
+        );
+
+        let population: Vec<_> = [0.1, 0.3]
+            .into_iter()
+            .map(|step_size| {
+                let mut chromosome: Chromosome = vec![1.0, 1.0].into_iter().collect();
+                chromosome.set_step_sizes(vec![step_size; 2]);
+
+                TestIndividual::create(chromosome)
+            })
+            .collect();
+
+        let (new_population, _) = ga.evolve(&mut rng, &population);
+
+        for individual in new_population {
+            let step_sizes = individual.chromosome().step_sizes();
+
+            assert!([[0.1, 0.1], [0.2, 0.2], [0.3, 0.3]]
+                .iter()
+                .any(|expected| approx::relative_eq!(step_sizes, expected.as_ref())));
+        }
+    }
+
+    #[test]
+    fn speciation_protects_small_species() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover,
+            UniformMutation::new(0.0, 0.0),
+        )
+        .with_speciation(Speciation::new(0.5));
+
+        // A single newcomer, doing slightly worse than the established
+        // species
+        let mut population = vec![individual(&[1.0, 1.0, 1.0]); 9];
+        population.push(individual(&[-2.0, 2.0, 2.5]));
+
+        let (new_population, stats) = ga.evolve(&mut rng, &population);
+
+        assert_eq!(stats.species_sizes(), &[9, 1]);
+
+        // After sharing, the newcomer is almost as fit as the entire
+        // established species - so it gets about as many offspring
+        let newcomers = new_population
+            .iter()
+            .filter(|individual| **individual == population[9])
+            .count();
+
+        assert_eq!(newcomers, 5);
+    }
+
+    #[test]
+    fn reproduction_strategy_replaces_the_pipeline() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover,
+            UniformMutation::new(0.0, 0.0),
+        )
+        .with_reproduction(DifferentialEvolution::new(
+            DifferentialEvolutionVariant::Best1Bin,
+            0.0,
+            1.0,
+        ));
+
+        let population = vec![
+            individual(&[0.0, 0.0, 0.0]), // fitness = 0.0
+            individual(&[1.0, 1.0, 1.0]), // fitness = 3.0
+            individual(&[1.0, 2.0, 1.0]), // fitness = 4.0
+            individual(&[1.0, 2.0, 4.0]), // fitness = 7.0
+        ];
+
+        let (new_population, stats) = ga.evolve(&mut rng, &population);
+
+        assert!(new_population
+            .iter()
+            .all(|individual| *individual == population[3]));
+
+        assert_eq!(stats.max_fitness(), 7.0);
+    }
+
+    #[test]
+    fn offspring_produces_a_single_child() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover,
+            UniformMutation::new(0.0, 0.0),
+        );
+
+        let population = vec![
+            individual(&[0.0, 0.0, 0.0]), // fitness = 0.0
+            individual(&[1.0, 2.0, 4.0]), // fitness = 7.0
+        ];
+
+        // The only parent with any fitness gets picked twice
+        let child = ga.offspring(&mut rng, &population);
+
+        assert_eq!(child, population[1]);
+    }
 }
@@ -1,8 +1,19 @@
-pub use guassian::*;
+pub use self::{normal::*, polynomial::*, reset::*, self_adaptive::*, uniform::*};
 
 use crate::*;
 
-mod guassian;
+mod normal;
+mod polynomial;
+mod reset;
+mod self_adaptive;
+mod uniform;
+
+/// Old name of [`UniformMutation`]; kept, so that existing code keeps
+/// compiling *and* mutating the way it used to.
+#[deprecated(
+    note = "renamed to `UniformMutation`; for normally distributed changes, see `NormalMutation`"
+)]
+pub type GaussianMutation = UniformMutation;
 
 pub trait MutationMethod {
     fn mutate(&self, rng: &mut dyn Rng, child: &mut Chromosome);
@@ -0,0 +1,96 @@
+use rand_distr::{Distribution, StandardNormal};
+
+use crate::*;
+
+/// Adds a normally distributed (Gaussian) number, with mean 0 and standard
+/// deviation `sigma`, to each touched gene.
+///
+/// Contrary to [`UniformMutation`], small changes are much more likely than
+/// large ones - but any change is possible.
+#[derive(Debug, Clone)]
+pub struct NormalMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Standard deviation of that change:
+    /// - 0.0 = touched genes will not be modified
+    /// - 1.0 = ~68% of touched genes will be += or -= by at most 1.0
+    sigma: f32,
+}
+
+impl NormalMutation {
+    pub fn new(chance: f32, sigma: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(sigma >= 0.0);
+
+        Self { chance, sigma }
+    }
+}
+
+impl MutationMethod for NormalMutation {
+    fn mutate(&self, rng: &mut dyn Rng, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.random_bool(self.chance as _) {
+                let change: f32 = StandardNormal.sample(rng);
+
+                *gene += self.sigma * change;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, sigma: f32) -> Vec<f32> {
+        let mut child = vec![1.0, 2.0, 3.0, 4.0, 5.0].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        NormalMutation::new(chance, sigma).mutate(&mut rng, &mut child);
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        let actual = actual(0.0, 0.5);
+        let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn given_zero_sigma_does_not_change_the_original_chromosome() {
+        let actual = actual(1.0, 0.0);
+        let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn given_max_chance_changes_the_original_chromosome() {
+        let actual = actual(1.0, 0.5);
+        let expected = vec![1.6888486, 2.2026734, 2.4018655, 3.0324764, 4.664113];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn changes_are_normally_distributed() {
+        let mut child: Chromosome = vec![0.0; 10_000].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        NormalMutation::new(1.0, 2.0).mutate(&mut rng, &mut child);
+
+        let len = child.len() as f32;
+        let mean = child.iter().sum::<f32>() / len;
+        let std_dev = (child.iter().map(|gene| (gene - mean).powi(2)).sum::<f32>() / len).sqrt();
+
+        approx::assert_relative_eq!(mean, 0.0, epsilon = 0.05);
+        approx::assert_relative_eq!(std_dev, 2.0, epsilon = 0.05);
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// Deb's polynomial mutation - the mutation counterpart of
+/// [`SimulatedBinaryCrossover`].
+///
+/// Each touched gene gets moved by a fraction of the `bounds`' width drawn
+/// from a polynomial distribution (controlled by `eta`), and is then
+/// clamped to stay within `bounds`.
+#[derive(Debug, Clone)]
+pub struct PolynomialMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Distribution index:
+    /// - small values (e.g. 5.0) = large changes are fairly common
+    /// - large values (e.g. 100.0) = (almost) only small changes happen
+    eta: f32,
+
+    /// Range genes are kept within.
+    bounds: RangeInclusive<f32>,
+}
+
+impl PolynomialMutation {
+    pub fn new(chance: f32, eta: f32, bounds: RangeInclusive<f32>) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(eta >= 0.0);
+        assert!(bounds.start() < bounds.end());
+
+        Self {
+            chance,
+            eta,
+            bounds,
+        }
+    }
+}
+
+impl MutationMethod for PolynomialMutation {
+    fn mutate(&self, rng: &mut dyn Rng, child: &mut Chromosome) {
+        let (min, max) = (*self.bounds.start(), *self.bounds.end());
+
+        for gene in child.iter_mut() {
+            if !rng.random_bool(self.chance as _) {
+                continue;
+            }
+
+            let u = rng.random::<f32>();
+
+            let delta = if u < 0.5 {
+                (2.0 * u).powf(1.0 / (self.eta + 1.0)) - 1.0
+            } else {
+                1.0 - (2.0 * (1.0 - u)).powf(1.0 / (self.eta + 1.0))
+            };
+
+            *gene = (*gene + delta * (max - min)).clamp(min, max);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, eta: f32) -> Vec<f32> {
+        let mut child = vec![-0.5, 0.0, 0.5, 1.0, -1.0].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        PolynomialMutation::new(chance, eta, -1.0..=1.0).mutate(&mut rng, &mut child);
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        let actual = actual(0.0, 20.0);
+        let expected = vec![-0.5, 0.0, 0.5, 1.0, -1.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn given_max_chance_changes_the_original_chromosome() {
+        let actual = actual(1.0, 20.0);
+        let expected = vec![-0.59139025, 0.10390508, 0.6559149, 1.0, -0.9305593];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn keeps_genes_within_bounds() {
+        for gene in actual(1.0, 0.0) {
+            assert!((-1.0..=1.0).contains(&gene));
+        }
+    }
+}
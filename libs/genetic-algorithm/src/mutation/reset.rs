@@ -0,0 +1,66 @@
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// Replaces each touched gene with a fresh one, drawn uniformly from
+/// `range` - usually the same range the initial population was drawn from.
+#[derive(Debug, Clone)]
+pub struct ResetMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Range new genes are drawn from.
+    range: RangeInclusive<f32>,
+}
+
+impl ResetMutation {
+    pub fn new(chance: f32, range: RangeInclusive<f32>) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(range.start() <= range.end());
+
+        Self { chance, range }
+    }
+}
+
+impl MutationMethod for ResetMutation {
+    fn mutate(&self, rng: &mut dyn Rng, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.random_bool(self.chance as _) {
+                *gene = rng.random_range(self.range.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32) -> Vec<f32> {
+        let mut child = vec![10.0, 20.0, 30.0, 40.0, 50.0].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        ResetMutation::new(chance, -1.0..=1.0).mutate(&mut rng, &mut child);
+        child.into_iter().collect()
+    }
+
+    #[test]
+    fn given_zero_chance_does_not_change_the_original_chromosome() {
+        let actual = actual(0.0);
+        let expected = vec![10.0, 20.0, 30.0, 40.0, 50.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn given_fifty_fifty_chance_resets_some_genes() {
+        let actual = actual(0.5);
+        let expected = vec![10.0, 20.0, 0.069369555, -0.48879623, 0.27546048];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,114 @@
+use rand_distr::{Distribution, StandardNormal};
+
+use crate::*;
+
+/// Self-adaptive Gaussian mutation, as in evolution strategies: each gene
+/// has its own step size, carried along in the chromosome (see
+/// [`Chromosome::step_sizes()`]), which gets mutated (log-normally) before
+/// the gene itself is.
+///
+/// Step sizes that lead to fit children get inherited together with the
+/// genes, so the population tunes its own mutation strength as it goes.
+#[derive(Debug, Clone)]
+pub struct SelfAdaptiveMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Step size given to chromosomes that don't have any yet.
+    initial_step_size: f32,
+
+    /// Lower bound for step sizes, so that genes never stop mutating
+    /// entirely.
+    min_step_size: f32,
+}
+
+impl SelfAdaptiveMutation {
+    /// Default lower bound for step sizes.
+    pub const MIN_STEP_SIZE: f32 = 1e-4;
+
+    pub fn new(chance: f32, initial_step_size: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+        assert!(initial_step_size > 0.0);
+
+        Self {
+            chance,
+            initial_step_size,
+            min_step_size: Self::MIN_STEP_SIZE,
+        }
+    }
+}
+
+impl MutationMethod for SelfAdaptiveMutation {
+    fn mutate(&self, rng: &mut dyn Rng, child: &mut Chromosome) {
+        if child.step_sizes().len() != child.len() {
+            child.set_step_sizes(vec![self.initial_step_size; child.len()]);
+        }
+
+        // Learning rates, as recommended by Schwefel
+        let n = child.len() as f32;
+        let global_tau = 1.0 / (2.0 * n).sqrt();
+        let local_tau = 1.0 / (2.0 * n.sqrt()).sqrt();
+
+        let global_change: f32 = StandardNormal.sample(rng);
+
+        let mut step_sizes = child.step_sizes().to_vec();
+
+        for (gene, step_size) in child.iter_mut().zip(&mut step_sizes) {
+            if !rng.random_bool(self.chance as _) {
+                continue;
+            }
+
+            let local_change: f32 = StandardNormal.sample(rng);
+            let gene_change: f32 = StandardNormal.sample(rng);
+
+            *step_size = (*step_size
+                * (global_tau * global_change + local_tau * local_change).exp())
+            .max(self.min_step_size);
+
+            *gene += *step_size * gene_change;
+        }
+
+        child.set_step_sizes(step_sizes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn initializes_missing_step_sizes() {
+        let mut child: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        SelfAdaptiveMutation::new(0.0, 0.5).mutate(&mut rng, &mut child);
+
+        approx::assert_relative_eq!(child.step_sizes(), [0.5, 0.5, 0.5].as_ref());
+        approx::assert_relative_eq!(child[0], 1.0);
+    }
+
+    #[test]
+    fn mutates_both_step_sizes_and_genes() {
+        let mut child: Chromosome = vec![1.0, 2.0, 3.0].into_iter().collect();
+        child.set_step_sizes(vec![0.1, 1.0, 10.0]);
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        SelfAdaptiveMutation::new(1.0, 0.5).mutate(&mut rng, &mut child);
+
+        let genes: Vec<_> = child.iter().copied().collect();
+
+        approx::assert_relative_eq!(
+            genes.as_slice(),
+            [0.73897743, 1.5831642, -18.285309].as_ref()
+        );
+        approx::assert_relative_eq!(
+            child.step_sizes(),
+            [0.21819726, 0.6205003, 9.90486].as_ref()
+        );
+    }
+}
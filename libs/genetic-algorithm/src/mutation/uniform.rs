@@ -0,0 +1,61 @@
+use crate::*;
+
+/// Adds a random number from `-coeff..coeff` to each touched gene.
+///
+/// (used to be called `GaussianMutation`, even though the perturbation is
+/// uniform, not normally distributed - see [`NormalMutation`] for that.)
+#[derive(Debug, Clone)]
+pub struct UniformMutation {
+    /// Probability of changing a gene:
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    chance: f32,
+
+    /// Magnitude of that change:
+    /// - 0.0 = touched genes will not be modified
+    /// - 1.0 = touched genes will be += or -= by at most 1.0
+    coeff: f32,
+}
+
+impl UniformMutation {
+    pub fn new(chance: f32, coeff: f32) -> Self {
+        assert!((0.0..=1.0).contains(&chance));
+
+        Self { chance, coeff }
+    }
+}
+
+impl MutationMethod for UniformMutation {
+    fn mutate(&self, rng: &mut dyn Rng, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            let sign = if rng.random_bool(0.5) { -1.0 } else { 1.0 };
+
+            if rng.random_bool(self.chance as _) {
+                *gene += sign * self.coeff * rng.random::<f32>();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    #[allow(deprecated)]
+    fn old_name_keeps_mutating_the_old_way() {
+        let mutate = |method: &dyn MutationMethod| {
+            let mut child: Chromosome = vec![1.0, 2.0, 3.0, 4.0, 5.0].into_iter().collect();
+
+            method.mutate(&mut ChaCha8Rng::from_seed(Default::default()), &mut child);
+            child.into_iter().collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            mutate(&GaussianMutation::new(1.0, 0.5)),
+            mutate(&UniformMutation::new(1.0, 0.5)),
+        );
+    }
+}
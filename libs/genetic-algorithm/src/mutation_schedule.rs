@@ -0,0 +1,22 @@
+pub use self::{exponential_decay::*, linear_decay::*, one_fifth_rule::*};
+
+use crate::*;
+
+mod exponential_decay;
+mod linear_decay;
+mod one_fifth_rule;
+
+/// Determines how strong mutations should be as the evolution progresses.
+///
+/// Strong mutations help to explore early on, but get disruptive once the
+/// population has found something that works - and the other way around.
+pub trait MutationSchedule {
+    /// Returns the multiplier for mutation's chance and magnitude to use
+    /// when breeding the population described by `statistics`:
+    /// - 1.0 = mutation is applied as configured
+    /// - 0.5 = mutation is half as likely and half as strong
+    ///
+    /// `generation` is the number of generations evolved so far (counting
+    /// the current one).
+    fn factor(&mut self, generation: usize, statistics: &Statistics) -> f32;
+}
@@ -0,0 +1,47 @@
+use crate::*;
+
+/// `factor = max(min, rate ^ generation)`
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExponentialDecay {
+    /// How much of the factor each generation keeps, e.g. 0.99 = 1% less
+    /// each generation.
+    rate: f32,
+
+    /// Lower bound for the factor, so that mutation never stops entirely.
+    min: f32,
+}
+
+impl ExponentialDecay {
+    pub fn new(rate: f32, min: f32) -> Self {
+        assert!(rate > 0.0 && rate <= 1.0);
+        assert!(min >= 0.0);
+
+        Self { rate, min }
+    }
+}
+
+impl MutationSchedule for ExponentialDecay {
+    fn factor(&mut self, generation: usize, _statistics: &Statistics) -> f32 {
+        self.rate.powi(generation as i32).max(self.min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let statistics = Statistics::new(&[TestIndividual::create([1.0].into_iter().collect())]);
+        let mut schedule = ExponentialDecay::new(0.5, 0.1);
+
+        let actual: Vec<_> = (0..=5)
+            .map(|generation| schedule.factor(generation, &statistics))
+            .collect();
+
+        let expected = vec![1.0, 0.5, 0.25, 0.125, 0.1, 0.1];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,52 @@
+use crate::*;
+
+/// Goes linearly from `from` to `to` over the first `generations`
+/// generations, and stays at `to` afterwards.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearDecay {
+    from: f32,
+    to: f32,
+    generations: usize,
+}
+
+impl LinearDecay {
+    pub fn new(from: f32, to: f32, generations: usize) -> Self {
+        assert!(from >= 0.0);
+        assert!(to >= 0.0);
+        assert!(generations > 0);
+
+        Self {
+            from,
+            to,
+            generations,
+        }
+    }
+}
+
+impl MutationSchedule for LinearDecay {
+    fn factor(&mut self, generation: usize, _statistics: &Statistics) -> f32 {
+        let progress = (generation as f32 / self.generations as f32).min(1.0);
+
+        self.from + (self.to - self.from) * progress
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let statistics = Statistics::new(&[TestIndividual::create([1.0].into_iter().collect())]);
+        let mut schedule = LinearDecay::new(1.0, 0.2, 4);
+
+        let actual: Vec<_> = (0..=6)
+            .map(|generation| schedule.factor(generation, &statistics))
+            .collect();
+
+        let expected = vec![1.0, 0.8, 0.6, 0.4, 0.2, 0.2, 0.2];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,174 @@
+use crate::*;
+
+/// Rechenberg's 1/5th success rule, adapted to populations.
+///
+/// A generation counts as a success when its average fitness is higher
+/// than the previous generation's. Every `window` generations the rule
+/// looks at how many of them were successful:
+///
+/// - more than 1/5 = mutations seem to be pulling their weight, so they
+///   get stronger (to get there faster),
+/// - less than 1/5 = mutations seem to be breaking more than they fix, so
+///   they get weaker (to fine-tune what's already there).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OneFifthRule {
+    /// How many generations are looked at before adjusting the factor.
+    window: usize,
+
+    /// How much the factor changes on each adjustment; e.g. 0.85 means the
+    /// factor gets divided by 0.85 when increasing, and multiplied by 0.85
+    /// when decreasing.
+    adjustment: f32,
+
+    /// Range the factor is kept within, so that a long stagnation doesn't
+    /// freeze mutations entirely (and a long streak of improvements
+    /// doesn't blow them up).
+    min_factor: f32,
+    max_factor: f32,
+
+    factor: f32,
+    successes: usize,
+    trials: usize,
+    previous_avg_fitness: Option<f32>,
+}
+
+impl OneFifthRule {
+    pub fn new(window: usize, adjustment: f32) -> Self {
+        assert!(window > 0);
+        assert!(adjustment > 0.0 && adjustment < 1.0);
+
+        Self {
+            window,
+            adjustment,
+            min_factor: 1e-3,
+            max_factor: 1e3,
+            factor: 1.0,
+            successes: 0,
+            trials: 0,
+            previous_avg_fitness: None,
+        }
+    }
+
+    pub fn with_bounds(mut self, min_factor: f32, max_factor: f32) -> Self {
+        assert!(min_factor > 0.0 && min_factor <= 1.0);
+        assert!(max_factor >= 1.0);
+
+        self.min_factor = min_factor;
+        self.max_factor = max_factor;
+        self.factor = self.factor.clamp(min_factor, max_factor);
+        self
+    }
+}
+
+impl Default for OneFifthRule {
+    fn default() -> Self {
+        Self::new(10, 0.85)
+    }
+}
+
+impl MutationSchedule for OneFifthRule {
+    fn factor(&mut self, _generation: usize, statistics: &Statistics) -> f32 {
+        let avg_fitness = statistics.avg_fitness();
+
+        if let Some(previous_avg_fitness) = self.previous_avg_fitness.replace(avg_fitness) {
+            self.trials += 1;
+
+            if avg_fitness > previous_avg_fitness {
+                self.successes += 1;
+            }
+        }
+
+        if self.trials >= self.window {
+            let success_rate = self.successes as f32 / self.trials as f32;
+
+            if success_rate > 0.2 {
+                self.factor /= self.adjustment;
+            } else if success_rate < 0.2 {
+                self.factor *= self.adjustment;
+            }
+
+            self.factor = self.factor.clamp(self.min_factor, self.max_factor);
+
+            self.successes = 0;
+            self.trials = 0;
+        }
+
+        self.factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn factors(avg_fitness: &[f32]) -> Vec<f32> {
+        factors_with(OneFifthRule::new(2, 0.5), avg_fitness)
+    }
+
+    fn factors_with(mut schedule: OneFifthRule, avg_fitness: &[f32]) -> Vec<f32> {
+        avg_fitness
+            .iter()
+            .enumerate()
+            .map(|(generation, &fitness)| {
+                schedule.factor(
+                    generation,
+                    &Statistics::new(&[TestIndividual::create([fitness].into_iter().collect())]),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn when_improving_increases_the_factor() {
+        let actual = factors(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let expected = vec![1.0, 1.0, 2.0, 2.0, 4.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn when_stagnating_decreases_the_factor() {
+        let actual = factors(&[1.0, 1.0, 1.0, 0.5, 0.5]);
+        let expected = vec![1.0, 1.0, 0.5, 0.5, 0.25];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn when_stagnating_for_long_stops_at_the_min_factor() {
+        let schedule = OneFifthRule::new(2, 0.5).with_bounds(0.25, 4.0);
+        let actual = factors_with(schedule, &[1.0; 9]);
+        let expected = vec![1.0, 1.0, 0.5, 0.5, 0.25, 0.25, 0.25, 0.25, 0.25];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn when_improving_for_long_stops_at_the_max_factor() {
+        let schedule = OneFifthRule::new(2, 0.5).with_bounds(0.25, 4.0);
+        let fitness: Vec<_> = (0..9).map(|n| n as f32).collect();
+        let actual = factors_with(schedule, &fitness);
+        let expected = vec![1.0, 1.0, 2.0, 2.0, 4.0, 4.0, 4.0, 4.0, 4.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn by_default_stays_within_sane_bounds() {
+        let fitness: Vec<_> = (0..100).map(|n| n as f32).collect();
+        let actual = factors_with(OneFifthRule::new(1, 0.5), &fitness);
+
+        approx::assert_relative_eq!(*actual.last().unwrap(), 1e3);
+
+        let actual = factors_with(OneFifthRule::new(1, 0.5), &[1.0; 100]);
+
+        approx::assert_relative_eq!(*actual.last().unwrap(), 1e-3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_inverted_bounds() {
+        OneFifthRule::default().with_bounds(2.0, 0.5);
+    }
+}
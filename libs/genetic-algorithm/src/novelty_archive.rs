@@ -0,0 +1,150 @@
+/// Archive of behaviours seen so far, used for novelty search - i.e.
+/// rewarding individuals for behaving differently than everybody else,
+/// instead of for reaching the objective.
+///
+/// Behaviours are described by vectors of numbers ("behaviour
+/// descriptors"), e.g. the final position of an individual; novelty of a
+/// behaviour is its average distance to the `k` nearest behaviours within
+/// the current population and the archive.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoveltyArchive {
+    /// How many nearest neighbours novelty is averaged over.
+    k: usize,
+
+    /// Behaviours more novel than this get added to the archive.
+    threshold: f32,
+
+    /// Maximum number of behaviours kept; when full, the oldest behaviours
+    /// get dropped first.
+    max_size: usize,
+
+    behaviours: Vec<Vec<f32>>,
+}
+
+impl NoveltyArchive {
+    pub fn new(k: usize, threshold: f32) -> Self {
+        assert!(k > 0);
+        assert!(threshold >= 0.0);
+
+        Self {
+            k,
+            threshold,
+            max_size: usize::MAX,
+            behaviours: Vec::new(),
+        }
+    }
+
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.behaviours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.behaviours.is_empty()
+    }
+
+    pub fn behaviours(&self) -> &[Vec<f32>] {
+        &self.behaviours
+    }
+
+    /// Returns novelty of each of the behaviours (coming from the current
+    /// population), and then archives the ones that are novel enough.
+    pub fn evaluate(&mut self, behaviours: &[Vec<f32>]) -> Vec<f32> {
+        let novelty: Vec<_> = behaviours
+            .iter()
+            .enumerate()
+            .map(|(idx, behaviour)| {
+                let mut distances: Vec<_> = behaviours
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_idx, _)| *other_idx != idx)
+                    .map(|(_, other)| other)
+                    .chain(&self.behaviours)
+                    .map(|other| behaviour_distance(behaviour, other))
+                    .collect();
+
+                distances.sort_by(f32::total_cmp);
+                distances.truncate(self.k);
+
+                if distances.is_empty() {
+                    0.0
+                } else {
+                    distances.iter().sum::<f32>() / (distances.len() as f32)
+                }
+            })
+            .collect();
+
+        for (behaviour, &novelty) in behaviours.iter().zip(&novelty) {
+            if novelty > self.threshold {
+                self.behaviours.push(behaviour.clone());
+            }
+        }
+
+        if self.behaviours.len() > self.max_size {
+            let excess = self.behaviours.len() - self.max_size;
+            self.behaviours.drain(..excess);
+        }
+
+        novelty
+    }
+}
+
+/// Returns Euclidean distance between two behaviour descriptors.
+pub fn behaviour_distance(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len());
+
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_behaviour_distance() {
+        assert_eq!(behaviour_distance(&[0.0, 0.0], &[3.0, 4.0]), 5.0);
+    }
+
+    #[test]
+    fn evaluate() {
+        let mut archive = NoveltyArchive::new(2, 1.5);
+
+        let behaviours = vec![vec![0.0], vec![1.0], vec![2.0], vec![10.0]];
+        let novelty = archive.evaluate(&behaviours);
+
+        approx::assert_relative_eq!(novelty.as_slice(), [1.5, 1.0, 1.5, 8.5].as_ref());
+        assert_eq!(archive.behaviours(), &[vec![10.0]]);
+
+        // Now that the archive remembers `10.0`, it's not that novel anymore
+        let novelty = archive.evaluate(&[vec![0.0], vec![10.0]]);
+
+        approx::assert_relative_eq!(novelty.as_slice(), [10.0, 5.0].as_ref());
+        assert_eq!(archive.behaviours(), &[vec![10.0], vec![0.0], vec![10.0]]);
+    }
+
+    #[test]
+    fn with_max_size_drops_the_oldest_behaviours() {
+        let mut archive = NoveltyArchive::new(1, 0.0).with_max_size(2);
+
+        archive.evaluate(&[vec![0.0], vec![1.0], vec![3.0]]);
+
+        assert_eq!(archive.behaviours(), &[vec![1.0], vec![3.0]]);
+    }
+
+    #[test]
+    fn single_behaviour_is_not_novel() {
+        let mut archive = NoveltyArchive::new(3, 0.0);
+
+        assert_eq!(archive.evaluate(&[vec![1.0, 2.0]]), vec![0.0]);
+        assert!(archive.is_empty());
+    }
+}
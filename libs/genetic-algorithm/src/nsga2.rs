@@ -0,0 +1,302 @@
+pub use self::{multi_objective_individual::*, pareto_front::*, sorting::*};
+
+use crate::*;
+
+mod multi_objective_individual;
+mod pareto_front;
+mod sorting;
+
+/// NSGA-II (Non-dominated Sorting Genetic Algorithm II) - evolves
+/// individuals judged by several objectives at once (see
+/// [`MultiObjectiveIndividual`]).
+///
+/// Individuals are ranked by the Pareto front they belong to and, within
+/// the same front, by their crowding distance (preferring individuals that
+/// are more unique); parents are then picked by binary tournaments over
+/// this ranking.
+///
+/// Since individuals get evaluated only after they are created (e.g. by
+/// living in a simulation), the algorithm remembers the survivors of the
+/// previous generation (together with their objectives) and merges them
+/// with the next population before sorting - that's the classic (μ+λ)
+/// survival, so a worse generation can't push the front backwards.
+///
+/// On top of that, elitism carries the best-ranked survivors over,
+/// unchanged, into the next generation.
+pub struct Nsga2 {
+    crossover_method: Box<dyn CrossoverMethod>,
+    mutation_method: Box<dyn MutationMethod>,
+    elitism: Elitism,
+
+    /// Survivors of the previous generation, with their objectives
+    parents: Vec<(Chromosome, Vec<f32>)>,
+}
+
+impl Nsga2 {
+    pub fn new(
+        crossover_method: impl CrossoverMethod + 'static,
+        mutation_method: impl MutationMethod + 'static,
+    ) -> Self {
+        Self {
+            crossover_method: Box::new(crossover_method),
+            mutation_method: Box::new(mutation_method),
+            elitism: Elitism::default(),
+            parents: Vec::new(),
+        }
+    }
+
+    pub fn with_elitism(mut self, elitism: Elitism) -> Self {
+        self.elitism = elitism;
+        self
+    }
+
+    /// Replaces the mutation method, e.g. to follow a [`MutationSchedule`].
+    pub fn set_mutation_method(&mut self, mutation_method: impl MutationMethod + 'static) {
+        self.mutation_method = Box::new(mutation_method);
+    }
+
+    /// Returns survivors of the previous generation, so that they can be
+    /// saved and later restored with [`Self::set_parents()`].
+    pub fn parents(&self) -> &[(Chromosome, Vec<f32>)] {
+        &self.parents
+    }
+
+    pub fn set_parents(&mut self, parents: Vec<(Chromosome, Vec<f32>)>) {
+        self.parents = parents;
+    }
+
+    pub fn evolve<I>(
+        &mut self,
+        rng: &mut dyn Rng,
+        population: &[I],
+    ) -> (Vec<I>, Statistics, ParetoFront)
+    where
+        I: MultiObjectiveIndividual,
+    {
+        assert!(!population.is_empty());
+
+        let objectives: Vec<_> = population
+            .iter()
+            .map(|individual| individual.objectives())
+            .collect();
+
+        let fronts = non_dominated_sort(&objectives);
+
+        // Parents that got carried over into this population have just been
+        // evaluated again, so it's their fresh objectives that count
+        let pool: Vec<_> = population
+            .iter()
+            .zip(&objectives)
+            .map(|(individual, objectives)| (individual.chromosome().clone(), objectives.clone()))
+            .chain(self.parents.drain(..).filter(|(parent, _)| {
+                !population
+                    .iter()
+                    .any(|individual| individual.chromosome().iter().eq(parent.iter()))
+            }))
+            .collect();
+
+        let pool_objectives: Vec<_> = pool
+            .iter()
+            .map(|(_, objectives)| objectives.clone())
+            .collect();
+
+        let mut rank = vec![0; pool.len()];
+        let mut crowding = vec![0.0; pool.len()];
+
+        for (front_rank, front) in non_dominated_sort(&pool_objectives).iter().enumerate() {
+            for (&idx, distance) in front.iter().zip(crowding_distance(&pool_objectives, front)) {
+                rank[idx] = front_rank;
+                crowding[idx] = distance;
+            }
+        }
+
+        // "Crowded comparison" - lower front first, more unique first
+        let is_better = |a: usize, b: usize| {
+            rank[a] < rank[b] || (rank[a] == rank[b] && crowding[a] > crowding[b])
+        };
+
+        let mut survivors: Vec<_> = (0..pool.len()).collect();
+
+        survivors.sort_by(|&a, &b| {
+            rank[a]
+                .cmp(&rank[b])
+                .then(crowding[b].total_cmp(&crowding[a]))
+        });
+
+        survivors.truncate(population.len());
+
+        let elites = self.elitism.count(population.len());
+
+        let tournament = |rng: &mut dyn Rng| {
+            let a = survivors[rng.random_range(0..survivors.len())];
+            let b = survivors[rng.random_range(0..survivors.len())];
+
+            if is_better(b, a) {
+                &pool[b].0
+            } else {
+                &pool[a].0
+            }
+        };
+
+        let offspring: Vec<_> = (elites..population.len())
+            .map(|_| {
+                let parent_a = tournament(rng);
+                let parent_b = tournament(rng);
+
+                let mut child = self.crossover_method.crossover(rng, parent_a, parent_b);
+
+                inherit_step_sizes(&mut child, parent_a, parent_b);
+
+                self.mutation_method.mutate(rng, &mut child);
+
+                I::create(child)
+            })
+            .collect();
+
+        let new_population = survivors[..elites]
+            .iter()
+            .map(|&idx| I::create(pool[idx].0.clone()))
+            .chain(offspring)
+            .collect();
+
+        self.parents = survivors.iter().map(|&idx| pool[idx].clone()).collect();
+
+        (
+            new_population,
+            Statistics::new(population),
+            ParetoFront::from_objectives(&objectives, &fronts),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn population() -> Vec<TestMultiObjectiveIndividual> {
+        vec![
+            TestMultiObjectiveIndividual::new(&[0.1, 0.1]),
+            TestMultiObjectiveIndividual::new(&[4.0, 0.0]),
+            TestMultiObjectiveIndividual::new(&[1.0, 1.0]),
+            TestMultiObjectiveIndividual::new(&[0.0, 4.0]),
+            TestMultiObjectiveIndividual::new(&[2.0, 2.0]),
+            TestMultiObjectiveIndividual::new(&[0.5, 0.5]),
+        ]
+    }
+
+    #[test]
+    fn reports_the_pareto_front() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut nsga2 = Nsga2::new(UniformCrossover, UniformMutation::new(0.0, 0.0));
+
+        let (new_population, stats, front) = nsga2.evolve(&mut rng, &population());
+
+        assert_eq!(new_population.len(), 6);
+        assert_eq!(stats.max_fitness(), 4.0);
+        assert_eq!(front.indices(), &[1, 3, 4]);
+    }
+
+    #[test]
+    fn elitism_keeps_the_pareto_front() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut nsga2 = Nsga2::new(UniformCrossover, UniformMutation::new(1.0, 0.5))
+            .with_elitism(Elitism::Count(3));
+
+        let (new_population, _, _) = nsga2.evolve(&mut rng, &population());
+
+        // Boundaries of the front go first (since they are infinitely
+        // uncrowded), then the rest of it
+        assert_eq!(
+            new_population[0],
+            TestMultiObjectiveIndividual::new(&[4.0, 0.0])
+        );
+        assert_eq!(
+            new_population[1],
+            TestMultiObjectiveIndividual::new(&[0.0, 4.0])
+        );
+        assert_eq!(
+            new_population[2],
+            TestMultiObjectiveIndividual::new(&[2.0, 2.0])
+        );
+    }
+
+    #[test]
+    fn front_survives_a_worse_generation() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut nsga2 = Nsga2::new(UniformCrossover, UniformMutation::new(0.0, 0.0))
+            .with_elitism(Elitism::Count(3));
+
+        nsga2.evolve(&mut rng, &population());
+
+        // Every individual here is dominated by every one of their parents
+        let worse_population = vec![TestMultiObjectiveIndividual::new(&[-1.0, -1.0]); 6];
+        let (new_population, _, _) = nsga2.evolve(&mut rng, &worse_population);
+
+        assert_eq!(
+            &new_population[..3],
+            &[
+                TestMultiObjectiveIndividual::new(&[4.0, 0.0]),
+                TestMultiObjectiveIndividual::new(&[0.0, 4.0]),
+                TestMultiObjectiveIndividual::new(&[2.0, 2.0]),
+            ]
+        );
+
+        assert!(new_population
+            .iter()
+            .all(|individual| !individual.chromosome().iter().any(|&gene| gene == -1.0)));
+    }
+
+    #[test]
+    fn parents_can_be_restored() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut nsga2 = Nsga2::new(UniformCrossover, UniformMutation::new(0.5, 0.5));
+
+        nsga2.evolve(&mut rng, &population());
+
+        let mut restored = Nsga2::new(UniformCrossover, UniformMutation::new(0.5, 0.5));
+
+        restored.set_parents(nsga2.parents().to_vec());
+
+        let population: Vec<_> = population().into_iter().rev().collect();
+
+        assert_eq!(
+            restored
+                .evolve(&mut ChaCha8Rng::from_seed(Default::default()), &population)
+                .0,
+            nsga2
+                .evolve(&mut ChaCha8Rng::from_seed(Default::default()), &population)
+                .0,
+        );
+    }
+
+    #[test]
+    fn offspring_come_mostly_from_the_front() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut nsga2 = Nsga2::new(UniformCrossover, UniformMutation::new(0.0, 0.0));
+
+        let population: Vec<_> = population().into_iter().cycle().take(60).collect();
+        let (new_population, _, _) = nsga2.evolve(&mut rng, &population);
+
+        // Individuals outside of the front have none of these genes
+        let front_genes = [0.0, 2.0, 4.0];
+
+        let from_front = new_population
+            .iter()
+            .filter(|individual| {
+                individual
+                    .chromosome()
+                    .iter()
+                    .all(|gene| front_genes.contains(gene))
+            })
+            .count();
+
+        // Half of the population forms the front, so picking parents at
+        // random would give ~25% of such children; binary tournaments pick
+        // a parent from the front 75% of the time, giving ~56%
+        assert!(from_front > 30, "got {from_front}");
+    }
+}
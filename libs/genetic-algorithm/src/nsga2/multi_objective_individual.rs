@@ -0,0 +1,53 @@
+use crate::*;
+
+/// Individual that's judged by several objectives at once, instead of a
+/// single fitness - e.g. "eats a lot" and "spends little energy".
+///
+/// [`Individual::fitness()`] is still used for [`Statistics`], so it should
+/// return whatever objective is the most representative.
+pub trait MultiObjectiveIndividual: Individual {
+    /// Returns objectives, all of which are to be maximized (so objectives
+    /// meant to be minimized should be negated).
+    ///
+    /// All individuals within a population must return the same number of
+    /// objectives.
+    fn objectives(&self) -> Vec<f32>;
+}
+
+#[cfg(test)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestMultiObjectiveIndividual {
+    pub chromosome: Chromosome,
+}
+
+#[cfg(test)]
+impl TestMultiObjectiveIndividual {
+    pub fn new(genes: &[f32]) -> Self {
+        Self {
+            chromosome: genes.iter().copied().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Individual for TestMultiObjectiveIndividual {
+    fn create(chromosome: Chromosome) -> Self {
+        Self { chromosome }
+    }
+
+    fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+
+    fn fitness(&self) -> f32 {
+        self.chromosome.iter().sum()
+    }
+}
+
+#[cfg(test)]
+impl MultiObjectiveIndividual for TestMultiObjectiveIndividual {
+    /// Genes are the objectives
+    fn objectives(&self) -> Vec<f32> {
+        self.chromosome.iter().copied().collect()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::*;
+
+/// Individuals of a population that aren't dominated by any other
+/// individual - i.e. the best trade-offs between the objectives found so
+/// far.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParetoFront {
+    indices: Vec<usize>,
+    objectives: Vec<Vec<f32>>,
+}
+
+impl ParetoFront {
+    pub fn new<I>(population: &[I]) -> Self
+    where
+        I: MultiObjectiveIndividual,
+    {
+        let objectives: Vec<_> = population
+            .iter()
+            .map(|individual| individual.objectives())
+            .collect();
+
+        Self::from_objectives(&objectives, &non_dominated_sort(&objectives))
+    }
+
+    pub(crate) fn from_objectives(objectives: &[Vec<f32>], fronts: &[Vec<usize>]) -> Self {
+        let indices = fronts.first().cloned().unwrap_or_default();
+
+        Self {
+            objectives: indices.iter().map(|&idx| objectives[idx].clone()).collect(),
+            indices,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns indices (into the evaluated population) of individuals
+    /// forming the front.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns objectives of individuals forming the front, in the same
+    /// order as [`Self::indices()`].
+    pub fn objectives(&self) -> &[Vec<f32>] {
+        &self.objectives
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let population = vec![
+            TestMultiObjectiveIndividual::new(&[1.0, 1.0]),
+            TestMultiObjectiveIndividual::new(&[3.0, 1.0]),
+            TestMultiObjectiveIndividual::new(&[1.0, 3.0]),
+            TestMultiObjectiveIndividual::new(&[2.0, 2.0]),
+        ];
+
+        let front = ParetoFront::new(&population);
+
+        assert_eq!(front.len(), 3);
+        assert_eq!(front.indices(), &[1, 2, 3]);
+        assert_eq!(
+            front.objectives(),
+            &[vec![3.0, 1.0], vec![1.0, 3.0], vec![2.0, 2.0]]
+        );
+    }
+}
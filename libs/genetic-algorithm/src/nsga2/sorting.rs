@@ -0,0 +1,146 @@
+/// Returns whether `a` dominates `b`, i.e. whether `a` is at least as good
+/// as `b` in all of the objectives and better in at least one of them.
+pub fn dominates(a: &[f32], b: &[f32]) -> bool {
+    assert_eq!(a.len(), b.len());
+
+    a.iter().zip(b).all(|(a, b)| a >= b) && a.iter().zip(b).any(|(a, b)| a > b)
+}
+
+/// Splits individuals (given by their objectives) into fronts: the first
+/// front contains individuals not dominated by anybody, the second front
+/// contains individuals dominated only by the first front, and so on.
+///
+/// Returns indices into `objectives`.
+pub fn non_dominated_sort(objectives: &[Vec<f32>]) -> Vec<Vec<usize>> {
+    // For each individual: who it dominates, and by how many it's dominated
+    let mut dominated = vec![Vec::new(); objectives.len()];
+    let mut domination_count = vec![0; objectives.len()];
+
+    for a in 0..objectives.len() {
+        for b in (a + 1)..objectives.len() {
+            if dominates(&objectives[a], &objectives[b]) {
+                dominated[a].push(b);
+                domination_count[b] += 1;
+            } else if dominates(&objectives[b], &objectives[a]) {
+                dominated[b].push(a);
+                domination_count[a] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+
+    let mut front: Vec<_> = (0..objectives.len())
+        .filter(|&idx| domination_count[idx] == 0)
+        .collect();
+
+    while !front.is_empty() {
+        let mut next_front = Vec::new();
+
+        for &a in &front {
+            for &b in &dominated[a] {
+                domination_count[b] -= 1;
+
+                if domination_count[b] == 0 {
+                    next_front.push(b);
+                }
+            }
+        }
+
+        next_front.sort_unstable();
+        fronts.push(front);
+        front = next_front;
+    }
+
+    fronts
+}
+
+/// Returns crowding distance of each individual in the front, i.e. how far
+/// apart its neighbours (within the front) are - the larger, the more
+/// unique the individual is.
+///
+/// Individuals on the boundaries of the front get an infinite distance, so
+/// that they are always preferred.
+pub fn crowding_distance(objectives: &[Vec<f32>], front: &[usize]) -> Vec<f32> {
+    let mut distance = vec![0.0; front.len()];
+
+    let Some(&first) = front.first() else {
+        return distance;
+    };
+
+    let values_per_objective = (0..objectives[first].len()).map(|objective| {
+        front
+            .iter()
+            .map(|&idx| objectives[idx][objective])
+            .collect::<Vec<_>>()
+    });
+
+    for values in values_per_objective {
+        let value = |pos: usize| values[pos];
+
+        let mut order: Vec<_> = (0..front.len()).collect();
+        order.sort_by(|&a, &b| value(a).total_cmp(&value(b)));
+
+        let (lo, hi) = (order[0], order[order.len() - 1]);
+        let range = value(hi) - value(lo);
+
+        distance[lo] = f32::INFINITY;
+        distance[hi] = f32::INFINITY;
+
+        if range <= 0.0 {
+            continue;
+        }
+
+        for window in order.windows(3) {
+            distance[window[1]] += (value(window[2]) - value(window[0])) / range;
+        }
+    }
+
+    distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominates() {
+        assert!(dominates(&[2.0, 2.0], &[1.0, 2.0]));
+        assert!(!dominates(&[2.0, 2.0], &[2.0, 2.0]));
+        assert!(!dominates(&[3.0, 1.0], &[1.0, 3.0]));
+        assert!(!dominates(&[1.0, 2.0], &[2.0, 2.0]));
+    }
+
+    #[test]
+    fn test_non_dominated_sort() {
+        let objectives = vec![
+            vec![1.0, 1.0], // dominated by #1, #2 and #4
+            vec![3.0, 1.0], // dominated by #4
+            vec![2.0, 3.0],
+            vec![0.0, 0.0], // dominated by everybody
+            vec![4.0, 2.0],
+        ];
+
+        assert_eq!(
+            non_dominated_sort(&objectives),
+            vec![vec![2, 4], vec![1], vec![0], vec![3]],
+        );
+    }
+
+    #[test]
+    fn test_crowding_distance() {
+        let objectives = vec![
+            vec![0.0, 4.0],
+            vec![1.0, 3.0],
+            vec![3.0, 1.0],
+            vec![4.0, 0.0],
+        ];
+
+        let distance = crowding_distance(&objectives, &[0, 1, 2, 3]);
+
+        assert_eq!(distance[0], f32::INFINITY);
+        assert_eq!(distance[1], 1.5);
+        assert_eq!(distance[2], 1.5);
+        assert_eq!(distance[3], f32::INFINITY);
+    }
+}
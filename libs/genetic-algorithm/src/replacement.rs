@@ -0,0 +1,138 @@
+use crate::*;
+
+/// Picks individuals to be replaced in steady-state evolution, where - instead
+/// of replacing the entire population at once - offspring replace
+/// individuals one at a time (see [`GeneticAlgorithm::offspring()`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Replacement {
+    /// The least fit individual gets replaced.
+    WorstOut,
+
+    /// The oldest individual gets replaced (see [`Individual::age()`]).
+    OldestOut,
+
+    /// The least fit of `size` randomly picked individuals gets replaced -
+    /// so that the worst individuals are likely, but not certain, to go.
+    TournamentLoser { size: usize },
+}
+
+impl Replacement {
+    /// Returns index of the individual to be replaced.
+    pub fn victim<I>(&self, rng: &mut dyn Rng, population: &[I]) -> usize
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let by_fitness = |a: &usize, b: &usize| {
+            population[*a]
+                .fitness()
+                .total_cmp(&population[*b].fitness())
+        };
+
+        match *self {
+            Self::WorstOut => (0..population.len()).min_by(by_fitness).unwrap(),
+
+            Self::OldestOut => (0..population.len())
+                .max_by_key(|&idx| population[idx].age())
+                .unwrap(),
+
+            Self::TournamentLoser { size } => {
+                assert!(size > 0);
+
+                (0..size)
+                    .map(|_| rng.random_range(0..population.len()))
+                    .min_by(by_fitness)
+                    .unwrap()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    struct AgingIndividual {
+        chromosome: Chromosome,
+        fitness: f32,
+        age: usize,
+    }
+
+    impl AgingIndividual {
+        fn new(fitness: f32, age: usize) -> Self {
+            Self {
+                chromosome: Chromosome::from_iter([fitness]),
+                fitness,
+                age,
+            }
+        }
+    }
+
+    impl Individual for AgingIndividual {
+        fn create(chromosome: Chromosome) -> Self {
+            Self {
+                fitness: chromosome.iter().sum(),
+                chromosome,
+                age: 0,
+            }
+        }
+
+        fn chromosome(&self) -> &Chromosome {
+            &self.chromosome
+        }
+
+        fn fitness(&self) -> f32 {
+            self.fitness
+        }
+
+        fn age(&self) -> usize {
+            self.age
+        }
+    }
+
+    fn population() -> Vec<AgingIndividual> {
+        vec![
+            AgingIndividual::new(3.0, 1),
+            AgingIndividual::new(1.0, 2),
+            AgingIndividual::new(4.0, 7),
+            AgingIndividual::new(2.0, 3),
+        ]
+    }
+
+    fn victim(replacement: Replacement) -> usize {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        replacement.victim(&mut rng, &population())
+    }
+
+    #[test]
+    fn worst_out() {
+        assert_eq!(victim(Replacement::WorstOut), 1);
+    }
+
+    #[test]
+    fn oldest_out() {
+        assert_eq!(victim(Replacement::OldestOut), 2);
+    }
+
+    #[test]
+    fn tournament_loser() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+
+        let mut histogram = [0; 4];
+
+        for _ in 0..1000 {
+            histogram[Replacement::TournamentLoser { size: 2 }.victim(&mut rng, &population)] += 1;
+        }
+
+        // The fitter, the less likely to get replaced
+        assert!(histogram[1] > histogram[3]);
+        assert!(histogram[3] > histogram[0]);
+        assert!(histogram[0] > histogram[2]);
+    }
+}
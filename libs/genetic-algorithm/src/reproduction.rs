@@ -0,0 +1,35 @@
+pub use self::differential_evolution::*;
+
+use crate::*;
+
+mod differential_evolution;
+
+/// Alternative way of producing offspring, replacing the usual
+/// selection -> crossover -> mutation pipeline of [`GeneticAlgorithm`]; see
+/// [`GeneticAlgorithm::with_reproduction()`].
+///
+/// Strategies see the entire population at once (so they can combine as
+/// many individuals as they need), and may keep track of previous
+/// generations.
+pub trait ReproductionStrategy {
+    /// Returns `count` offspring, given the population - that is, each
+    /// individual's chromosome together with its (raw, i.e. not scaled)
+    /// fitness.
+    fn reproduce(
+        &mut self,
+        rng: &mut dyn Rng,
+        population: &[(&Chromosome, f32)],
+        count: usize,
+    ) -> Vec<Chromosome>;
+
+    /// Returns individuals (with their fitness) the strategy carries over
+    /// between generations, so that it can be saved and later restored
+    /// with [`Self::set_state()`].
+    fn state(&self) -> Vec<(Chromosome, f32)> {
+        Vec::new()
+    }
+
+    fn set_state(&mut self, _state: Vec<(Chromosome, f32)>) {
+        //
+    }
+}
@@ -0,0 +1,312 @@
+use rand::seq::index;
+
+use crate::*;
+
+/// Differential evolution - creates each offspring ("trial") by adding
+/// scaled differences between random individuals to a base individual, and
+/// then crossing the result over with the individual it is meant to
+/// replace ("target").
+///
+/// Since individuals get evaluated only after they are created, trials are
+/// compared with their targets a generation later: each trial that turns
+/// out to be at least as fit as its target replaces it, otherwise the
+/// target is kept. This makes differential evolution elitist on its own,
+/// and it requires offspring to stay in the order they have been created
+/// in - so it doesn't support elitism.
+#[derive(Clone, Debug)]
+pub struct DifferentialEvolution {
+    variant: DifferentialEvolutionVariant,
+
+    /// Differential weight, i.e. how much differences between individuals
+    /// get scaled by; usually within `0.4..=1.0`.
+    f: f32,
+
+    /// Crossover probability, i.e. the chance of each gene coming from the
+    /// mutant rather than from the target:
+    /// - 0.0 = only one (random) gene comes from the mutant
+    /// - 1.0 = all genes come from the mutant
+    cr: f32,
+
+    /// Current targets, with their fitness
+    targets: Vec<(Chromosome, f32)>,
+}
+
+/// How the mutant (the vector that gets crossed over with the target) is
+/// computed; `r1`, `r2` and `r3` are distinct random individuals, other
+/// than the target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DifferentialEvolutionVariant {
+    /// DE/rand/1/bin: `r1 + F * (r2 - r3)`
+    Rand1Bin,
+
+    /// DE/best/1/bin: `best + F * (r1 - r2)`
+    Best1Bin,
+
+    /// DE/current-to-best/1(/bin): `target + F * (best - target) + F * (r1 - r2)`
+    CurrentToBest1,
+}
+
+impl DifferentialEvolution {
+    pub fn new(variant: DifferentialEvolutionVariant, f: f32, cr: f32) -> Self {
+        assert!(f >= 0.0);
+        assert!((0.0..=1.0).contains(&cr));
+
+        Self {
+            variant,
+            f,
+            cr,
+            targets: Vec::new(),
+        }
+    }
+
+    fn trial(&self, rng: &mut dyn Rng, target: usize, best: usize) -> Chromosome {
+        let others: Vec<_> = index::sample(rng, self.targets.len(), 4)
+            .into_iter()
+            .filter(|&idx| idx != target)
+            .take(3)
+            .collect();
+
+        let gene = |idx: usize, gene: usize| self.targets[idx].0[gene];
+        let (r1, r2, r3) = (others[0], others[1], others[2]);
+        let len = self.targets[target].0.len();
+        let forced_gene = rng.random_range(0..len);
+
+        (0..len)
+            .map(|j| {
+                let from_mutant = j == forced_gene || rng.random_bool(self.cr as _);
+
+                if !from_mutant {
+                    return gene(target, j);
+                }
+
+                match self.variant {
+                    DifferentialEvolutionVariant::Rand1Bin => {
+                        gene(r1, j) + self.f * (gene(r2, j) - gene(r3, j))
+                    }
+                    DifferentialEvolutionVariant::Best1Bin => {
+                        gene(best, j) + self.f * (gene(r1, j) - gene(r2, j))
+                    }
+                    DifferentialEvolutionVariant::CurrentToBest1 => {
+                        gene(target, j)
+                            + self.f * (gene(best, j) - gene(target, j))
+                            + self.f * (gene(r1, j) - gene(r2, j))
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl ReproductionStrategy for DifferentialEvolution {
+    fn reproduce(
+        &mut self,
+        rng: &mut dyn Rng,
+        population: &[(&Chromosome, f32)],
+        count: usize,
+    ) -> Vec<Chromosome> {
+        assert_eq!(
+            count,
+            population.len(),
+            "differential evolution doesn't support elitism"
+        );
+
+        assert!(
+            population.len() >= 4,
+            "differential evolution needs at least four individuals"
+        );
+
+        if self.targets.len() == population.len() {
+            // Trial-versus-target replacement
+            for (target, &(trial, fitness)) in self.targets.iter_mut().zip(population) {
+                if fitness >= target.1 {
+                    *target = (trial.clone(), fitness);
+                }
+            }
+        } else {
+            // First generation (or the population has changed its size) -
+            // whatever we've got becomes the targets
+            self.targets = population
+                .iter()
+                .map(|&(chromosome, fitness)| (chromosome.clone(), fitness))
+                .collect();
+        }
+
+        let best = (0..self.targets.len())
+            .max_by(|&a, &b| self.targets[a].1.total_cmp(&self.targets[b].1))
+            .unwrap();
+
+        (0..self.targets.len())
+            .map(|target| self.trial(rng, target, best))
+            .collect()
+    }
+
+    fn state(&self) -> Vec<(Chromosome, f32)> {
+        self.targets.clone()
+    }
+
+    fn set_state(&mut self, state: Vec<(Chromosome, f32)>) {
+        self.targets = state;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn chromosome(genes: &[f32]) -> Chromosome {
+        genes.iter().copied().collect()
+    }
+
+    fn population() -> Vec<Chromosome> {
+        vec![
+            chromosome(&[0.0, 0.0]),
+            chromosome(&[1.0, 0.0]),
+            chromosome(&[0.0, 1.0]),
+            chromosome(&[1.0, 1.0]),
+            chromosome(&[2.0, 2.0]),
+        ]
+    }
+
+    fn reproduce(
+        de: &mut DifferentialEvolution,
+        population: &[Chromosome],
+        fitness: &[f32],
+    ) -> Vec<Chromosome> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let population: Vec<_> = population.iter().zip(fitness.iter().copied()).collect();
+
+        de.reproduce(&mut rng, &population, population.len())
+    }
+
+    #[test]
+    fn with_zero_weight_rand1bin_copies_random_individuals() {
+        let mut de = DifferentialEvolution::new(DifferentialEvolutionVariant::Rand1Bin, 0.0, 1.0);
+        let population = population();
+
+        let trials = reproduce(&mut de, &population, &[0.0, 1.0, 1.0, 2.0, 4.0]);
+
+        assert_eq!(trials.len(), 5);
+
+        for (idx, trial) in trials.iter().enumerate() {
+            let source = population.iter().position(|individual| individual == trial);
+
+            assert!(source.is_some());
+            assert_ne!(source, Some(idx));
+        }
+    }
+
+    #[test]
+    fn with_zero_weight_best1bin_copies_the_best_individual() {
+        let mut de = DifferentialEvolution::new(DifferentialEvolutionVariant::Best1Bin, 0.0, 1.0);
+
+        let trials = reproduce(&mut de, &population(), &[0.0, 1.0, 1.0, 2.0, 4.0]);
+
+        assert!(trials.iter().all(|trial| *trial == chromosome(&[2.0, 2.0])));
+    }
+
+    #[test]
+    fn current_to_best1_moves_towards_the_best() {
+        let mut de =
+            DifferentialEvolution::new(DifferentialEvolutionVariant::CurrentToBest1, 0.5, 1.0);
+
+        let trials = reproduce(&mut de, &population(), &[0.0, 1.0, 1.0, 2.0, 4.0]);
+
+        // Target #0 = (0, 0) and best = (2, 2), so without the random
+        // difference the trial would be right in the middle
+        let (x, y) = (trials[0][0], trials[0][1]);
+
+        assert!((0.0..=2.0).contains(&x), "got {x}");
+        assert!((0.0..=2.0).contains(&y), "got {y}");
+    }
+
+    #[test]
+    fn with_zero_crossover_probability_changes_a_single_gene() {
+        let mut de = DifferentialEvolution::new(DifferentialEvolutionVariant::Rand1Bin, 0.8, 0.0);
+        let population = population();
+
+        let trials = reproduce(&mut de, &population, &[0.0, 1.0, 1.0, 2.0, 4.0]);
+
+        for (trial, target) in trials.iter().zip(&population) {
+            let kept = trial
+                .iter()
+                .zip(target.iter())
+                .filter(|(a, b)| a == b)
+                .count();
+
+            assert!(kept >= 1);
+        }
+    }
+
+    #[test]
+    fn trials_replace_targets_only_when_at_least_as_fit() {
+        let mut de = DifferentialEvolution::new(DifferentialEvolutionVariant::Best1Bin, 0.0, 1.0);
+
+        reproduce(&mut de, &population(), &[0.0, 1.0, 1.0, 2.0, 4.0]);
+
+        let trials = vec![
+            chromosome(&[5.0, 5.0]), // better than its target
+            chromosome(&[6.0, 6.0]), // worse than its target
+            chromosome(&[7.0, 7.0]), // as good as its target
+            chromosome(&[8.0, 8.0]),
+            chromosome(&[9.0, 9.0]),
+        ];
+
+        reproduce(&mut de, &trials, &[0.5, 0.5, 1.0, 0.0, 0.0]);
+
+        let targets: Vec<_> = de
+            .targets
+            .iter()
+            .map(|(chromosome, _)| chromosome.clone())
+            .collect();
+
+        assert_eq!(
+            targets,
+            vec![
+                chromosome(&[5.0, 5.0]),
+                chromosome(&[1.0, 0.0]),
+                chromosome(&[7.0, 7.0]),
+                chromosome(&[1.0, 1.0]),
+                chromosome(&[2.0, 2.0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn state_can_be_restored() {
+        let mut de = DifferentialEvolution::new(DifferentialEvolutionVariant::Rand1Bin, 0.5, 0.9);
+        let fitness = [0.0, 1.0, 1.0, 2.0, 4.0];
+
+        reproduce(&mut de, &population(), &fitness);
+
+        let mut restored =
+            DifferentialEvolution::new(DifferentialEvolutionVariant::Rand1Bin, 0.5, 0.9);
+
+        restored.set_state(de.state());
+
+        assert_eq!(
+            reproduce(&mut restored, &population(), &fitness),
+            reproduce(&mut de, &population(), &fitness),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_elitism() {
+        let mut de = DifferentialEvolution::new(DifferentialEvolutionVariant::Rand1Bin, 0.5, 0.5);
+        let population = population();
+        let population: Vec<_> = population
+            .iter()
+            .map(|chromosome| (chromosome, 0.0))
+            .collect();
+
+        de.reproduce(
+            &mut ChaCha8Rng::from_seed(Default::default()),
+            &population,
+            4,
+        );
+    }
+}
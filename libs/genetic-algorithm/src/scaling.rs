@@ -0,0 +1,24 @@
+pub use self::{
+    exponential_ranking::*, linear::*, no_scaling::*, sigma_truncation::*, windowing::*,
+};
+
+mod exponential_ranking;
+mod linear;
+mod no_scaling;
+mod sigma_truncation;
+mod windowing;
+
+/// Transforms raw fitness values before they are seen by the selection
+/// method, allowing to tune the selection pressure independently of how
+/// the fitness itself is computed.
+pub trait FitnessScaling {
+    /// Returns scaled fitness values, one per each of the given raw ones
+    /// (in the same order).
+    ///
+    /// Scaled values must not be negative.
+    fn scale(&self, fitness: &[f32]) -> Vec<f32>;
+}
+
+fn mean(fitness: &[f32]) -> f32 {
+    fitness.iter().sum::<f32>() / (fitness.len() as f32)
+}
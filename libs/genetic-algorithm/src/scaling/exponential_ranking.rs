@@ -0,0 +1,56 @@
+use crate::*;
+
+/// Replaces fitness with `base ^ rank`, where the best individual has rank
+/// 0, the second best has rank 1 and so on.
+///
+/// Only the order of individuals matters then, not how far apart their raw
+/// fitness values are.
+#[derive(Clone, Debug)]
+pub struct ExponentialRanking {
+    /// How much of the fitness each consecutive rank keeps:
+    /// - close to 0.0 = (almost) only the best individual gets picked
+    /// - 1.0 = everybody gets the same fitness (no selection pressure)
+    base: f32,
+}
+
+impl ExponentialRanking {
+    pub fn new(base: f32) -> Self {
+        assert!(base > 0.0 && base <= 1.0);
+
+        Self { base }
+    }
+}
+
+impl Default for ExponentialRanking {
+    fn default() -> Self {
+        Self::new(0.9)
+    }
+}
+
+impl FitnessScaling for ExponentialRanking {
+    fn scale(&self, fitness: &[f32]) -> Vec<f32> {
+        let mut ranked: Vec<_> = (0..fitness.len()).collect();
+        ranked.sort_by(|&a, &b| fitness[b].total_cmp(&fitness[a]));
+
+        let mut scaled = vec![0.0; fitness.len()];
+
+        for (rank, idx) in ranked.into_iter().enumerate() {
+            scaled[idx] = self.base.powi(rank as i32);
+        }
+
+        scaled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let actual = ExponentialRanking::new(0.5).scale(&[2.0, 1.0, 400.0, 3.0]);
+        let expected = vec![0.25, 0.125, 1.0, 0.5];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,94 @@
+use super::mean;
+use crate::*;
+
+/// Goldberg's linear scaling: `f' = a * f + b`, with `a` and `b` chosen so
+/// that the average fitness stays the same, while the best individual
+/// gets `multiplier` times the average.
+///
+/// When that would push the worst individual below zero, the scaling is
+/// toned down so that the worst individual ends up with zero instead.
+#[derive(Clone, Debug)]
+pub struct LinearScaling {
+    /// Fitness of the best individual, in multiples of the average:
+    /// - 1.0 = everybody gets the average fitness (no selection pressure)
+    /// - 2.0 = the best individual is expected to get picked twice as
+    ///   often as an average one
+    multiplier: f32,
+}
+
+impl LinearScaling {
+    pub fn new(multiplier: f32) -> Self {
+        assert!(multiplier >= 1.0);
+
+        Self { multiplier }
+    }
+}
+
+impl Default for LinearScaling {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+impl FitnessScaling for LinearScaling {
+    fn scale(&self, fitness: &[f32]) -> Vec<f32> {
+        if fitness.is_empty() {
+            return Vec::new();
+        }
+
+        let avg = mean(fitness);
+        let min = fitness.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = fitness.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+        // Everybody's equally fit, there's nothing to scale
+        if max - avg <= f32::EPSILON {
+            return vec![avg.max(0.0); fitness.len()];
+        }
+
+        let delta = max - avg;
+        let mut a = (self.multiplier - 1.0) * avg / delta;
+        let mut b = avg * (max - self.multiplier * avg) / delta;
+
+        if a * min + b < 0.0 {
+            let delta = avg - min;
+
+            a = avg / delta;
+            b = -min * avg / delta;
+        }
+
+        fitness.iter().map(|f| (a * f + b).max(0.0)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let actual = LinearScaling::new(2.0).scale(&[4.0, 5.0, 6.0, 5.0]);
+
+        // Average stays at 5.0, while the best individual gets 2 * 5.0
+        let expected = vec![0.0, 5.0, 10.0, 5.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn keeps_the_worst_individual_at_zero() {
+        // avg = 7.5, so the best individual would get 15.0 - but that'd
+        // require giving the worst one -9.0
+        let actual = LinearScaling::new(2.0).scale(&[2.0, 9.0, 9.0, 10.0]);
+        let expected = vec![0.0, 9.545_454, 9.545_454, 10.909_091];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn with_equal_fitness() {
+        let actual = LinearScaling::new(2.0).scale(&[3.0, 3.0, 3.0]);
+        let expected = vec![3.0, 3.0, 3.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::*;
+
+/// Leaves the fitness values as they are.
+#[derive(Clone, Debug, Default)]
+pub struct NoScaling;
+
+impl FitnessScaling for NoScaling {
+    fn scale(&self, fitness: &[f32]) -> Vec<f32> {
+        fitness.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let actual = NoScaling.scale(&[2.0, 1.0, 4.0, 3.0]);
+        let expected = vec![2.0, 1.0, 4.0, 3.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+}
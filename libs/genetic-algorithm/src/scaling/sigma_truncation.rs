@@ -0,0 +1,70 @@
+use super::mean;
+use crate::*;
+
+/// `f' = max(0, f - (avg - c * std_dev))`
+///
+/// Makes selection pressure independent of how spread out the raw fitness
+/// values are: individuals more than `c` standard deviations below the
+/// average get zero, the rest are shifted accordingly.
+#[derive(Clone, Debug)]
+pub struct SigmaTruncation {
+    /// How many standard deviations below the average an individual can be
+    /// and still have a chance of getting picked; usually between 1 and 3.
+    c: f32,
+}
+
+impl SigmaTruncation {
+    pub fn new(c: f32) -> Self {
+        assert!(c >= 0.0);
+
+        Self { c }
+    }
+}
+
+impl Default for SigmaTruncation {
+    fn default() -> Self {
+        Self::new(2.0)
+    }
+}
+
+impl FitnessScaling for SigmaTruncation {
+    fn scale(&self, fitness: &[f32]) -> Vec<f32> {
+        if fitness.is_empty() {
+            return Vec::new();
+        }
+
+        let avg = mean(fitness);
+
+        let std_dev = (fitness.iter().map(|f| (f - avg).powi(2)).sum::<f32>()
+            / (fitness.len() as f32))
+            .sqrt();
+
+        let threshold = avg - self.c * std_dev;
+
+        fitness.iter().map(|f| (f - threshold).max(0.0)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        // avg = 5.0, std_dev = 2.0
+        let actual = SigmaTruncation::new(0.5).scale(&[3.0, 7.0, 3.0, 7.0]);
+
+        // threshold = 5.0 - 0.5 * 2.0 = 4.0
+        let expected = vec![0.0, 3.0, 0.0, 3.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn with_equal_fitness() {
+        let actual = SigmaTruncation::new(2.0).scale(&[3.0, 3.0, 3.0]);
+        let expected = vec![0.0, 0.0, 0.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,52 @@
+use crate::*;
+
+/// `f' = f - min`
+///
+/// Subtracts the fitness of the worst individual in the population, so that
+/// what matters is how much better than the worst one an individual is -
+/// when everybody's got a lot of fitness (e.g. 100, 101 and 102), the
+/// differences become as important as when they've got little (0, 1, 2).
+#[derive(Clone, Debug, Default)]
+pub struct Windowing {
+    /// Added to every scaled value, so that the worst individual still has
+    /// some chance of getting picked:
+    /// - 0.0 = the worst individual is never picked
+    offset: f32,
+}
+
+impl Windowing {
+    pub fn new(offset: f32) -> Self {
+        assert!(offset >= 0.0);
+
+        Self { offset }
+    }
+}
+
+impl FitnessScaling for Windowing {
+    fn scale(&self, fitness: &[f32]) -> Vec<f32> {
+        let min = fitness.iter().copied().fold(f32::INFINITY, f32::min);
+
+        fitness.iter().map(|f| f - min + self.offset).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let actual = Windowing::new(0.0).scale(&[101.0, 100.0, 104.0, 103.0]);
+        let expected = vec![1.0, 0.0, 4.0, 3.0];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn with_offset() {
+        let actual = Windowing::new(0.5).scale(&[-1.0, -2.0, 2.0]);
+        let expected = vec![1.5, 0.5, 4.5];
+
+        approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+}
@@ -1,11 +1,36 @@
-pub use self::roulette_wheel::*;
+pub use self::{
+    linear_rank::*, roulette_wheel::*, stochastic_universal_sampling::*, tournament::*,
+};
 
 use crate::*;
 
+mod linear_rank;
 mod roulette_wheel;
+mod stochastic_universal_sampling;
+mod tournament;
 
 pub trait SelectionMethod {
     fn select<'a, I>(&self, rng: &mut dyn Rng, population: &'a [I]) -> &'a I
     where
         I: Individual;
+
+    /// Selects the whole mating pool - `count` parents - at once.
+    ///
+    /// By default it's just `.select()` called `count` times; methods that
+    /// pick all the parents in one go (such as SUS) override it, together
+    /// with [`Self::selects_many_at_once()`].
+    fn select_many<'a, I>(&self, rng: &mut dyn Rng, population: &'a [I], count: usize) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        (0..count).map(|_| self.select(rng, population)).collect()
+    }
+
+    /// Returns whether [`Self::select_many()`] is what this method should
+    /// be driven by; if so, the genetic algorithm draws the entire mating
+    /// pool first, and breeds afterwards - otherwise parents get selected
+    /// pair by pair, right before breeding each child.
+    fn selects_many_at_once(&self) -> bool {
+        false
+    }
 }
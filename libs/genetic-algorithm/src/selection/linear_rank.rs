@@ -0,0 +1,157 @@
+use crate::*;
+use rand::distr::{weighted::WeightedIndex, Distribution};
+
+#[derive(Clone, Debug)]
+pub struct LinearRankSelection {
+    /// Expected number of times the fittest individual gets selected,
+    /// per one selection of an average individual:
+    /// - 1.0 = no selection pressure at all (uniformly random pick)
+    /// - 2.0 = maximum pressure; the least fit individual is never picked
+    selection_pressure: f32,
+}
+
+impl LinearRankSelection {
+    pub fn new(selection_pressure: f32) -> Self {
+        assert!((1.0..=2.0).contains(&selection_pressure));
+
+        Self { selection_pressure }
+    }
+}
+
+impl Default for LinearRankSelection {
+    fn default() -> Self {
+        Self::new(1.5)
+    }
+}
+
+impl SelectionMethod for LinearRankSelection {
+    fn select<'a, I>(&self, rng: &mut dyn Rng, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    /// Ranks the population once, and then picks all the parents from that
+    /// ranking.
+    fn select_many<'a, I>(&self, rng: &mut dyn Rng, population: &'a [I], count: usize) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty(), "got an empty population");
+
+        // Least fit individuals go first, so that an individual's rank is
+        // simply its index
+        let mut ranked: Vec<_> = population.iter().collect();
+        ranked.sort_by(|a, b| a.fitness().total_cmp(&b.fitness()));
+
+        if ranked.len() == 1 {
+            return vec![ranked[0]; count];
+        }
+
+        let max_rank = (ranked.len() - 1) as f32;
+        let sp = self.selection_pressure;
+
+        let weights =
+            (0..ranked.len()).map(|rank| (2.0 - sp) + 2.0 * (sp - 1.0) * (rank as f32) / max_rank);
+
+        let ranks = WeightedIndex::new(weights).expect("got an empty population");
+
+        (0..count).map(|_| ranked[ranks.sample(rng)]).collect()
+    }
+
+    fn selects_many_at_once(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn histogram(method: LinearRankSelection, population: &[TestIndividual]) -> BTreeMap<i32, i32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        (0..1000).map(|_| method.select(&mut rng, population)).fold(
+            Default::default(),
+            |mut histogram, individual| {
+                *histogram.entry(individual.fitness() as _).or_default() += 1;
+
+                histogram
+            },
+        )
+    }
+
+    #[test]
+    fn test() {
+        let population = vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(40.0),
+            TestIndividual::new(3.0),
+        ];
+
+        let actual_histogram = histogram(LinearRankSelection::new(2.0), &population);
+
+        let expected_histogram = maplit::btreemap! {
+            // fitness => how many times this fitness has been chosen
+            2 => 164,
+            3 => 337,
+            40 => 499,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn without_selection_pressure() {
+        let population = vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(40.0),
+            TestIndividual::new(3.0),
+        ];
+
+        let actual_histogram = histogram(LinearRankSelection::new(1.0), &population);
+
+        let expected_histogram = maplit::btreemap! {
+            1 => 248,
+            2 => 253,
+            3 => 270,
+            40 => 229,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn select_many_matches_repeated_select() {
+        let population: Vec<_> = (0..20).map(|n| TestIndividual::new(n as f32)).collect();
+        let method = LinearRankSelection::default();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let expected: Vec<_> = (0..10)
+            .map(|_| method.select(&mut rng, &population).fitness())
+            .collect();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let actual: Vec<_> = method
+            .select_many(&mut rng, &population, 10)
+            .into_iter()
+            .map(|individual| individual.fitness())
+            .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_too_high_selection_pressure() {
+        LinearRankSelection::new(2.5);
+    }
+}
@@ -1,3 +1,5 @@
+use rand::distr::weighted::Error as WeightError;
+
 use crate::*;
 
 pub struct RouletteWheelSelection;
@@ -19,9 +21,18 @@ impl SelectionMethod for RouletteWheelSelection {
     where
         I: Individual,
     {
-        population
-            .choose_weighted(rng, |individual| individual.fitness())
-            .expect("got an empty population")
+        assert!(!population.is_empty(), "got an empty population");
+
+        match population.choose_weighted(rng, |individual| individual.fitness()) {
+            Ok(individual) => individual,
+
+            // Happens when every individual has zero fitness; since
+            // everybody's equally unfit, everybody's equally likely to get
+            // picked
+            Err(WeightError::InsufficientNonZero) => population.choose(rng).unwrap(),
+
+            Err(err) => panic!("couldn't select an individual: {err}"),
+        }
     }
 }
 
@@ -64,4 +75,49 @@ mod tests {
 
         assert_eq!(actual_histogram, expected_histogram);
     }
+
+    #[test]
+    fn with_zero_fitness() {
+        let method = RouletteWheelSelection::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+        ];
+
+        let actual_histogram: BTreeMap<usize, _> = (0..1000)
+            .map(|_| method.select(&mut rng, &population))
+            .fold(Default::default(), |mut histogram, individual| {
+                let idx = population
+                    .iter()
+                    .position(|other| std::ptr::eq(individual, other))
+                    .unwrap();
+
+                *histogram.entry(idx).or_default() += 1;
+
+                histogram
+            });
+
+        let expected_histogram = maplit::btreemap! {
+            // individual's index => how many times it has been chosen
+            0 => 248,
+            1 => 253,
+            2 => 270,
+            3 => 229,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    #[should_panic(expected = "got an empty population")]
+    fn with_empty_population() {
+        let method = RouletteWheelSelection::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        method.select::<TestIndividual>(&mut rng, &[]);
+    }
 }
@@ -0,0 +1,186 @@
+use rand::seq::SliceRandom;
+
+use crate::*;
+
+/// Roulette wheel with `count` equally spaced pointers, spun just once.
+///
+/// Compared to spinning the regular roulette wheel `count` times, it
+/// guarantees that each individual gets picked (almost exactly) as many
+/// times as its share of the total fitness says - there's no "bad luck"
+/// of a fit individual not getting picked at all.
+#[derive(Clone, Debug, Default)]
+pub struct StochasticUniversalSampling;
+
+impl StochasticUniversalSampling {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SelectionMethod for StochasticUniversalSampling {
+    fn select<'a, I>(&self, rng: &mut dyn Rng, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn select_many<'a, I>(&self, rng: &mut dyn Rng, population: &'a [I], count: usize) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty(), "got an empty population");
+
+        let mut total_fitness: f32 = population
+            .iter()
+            .map(|individual| individual.fitness())
+            .sum();
+
+        // Happens when every individual has zero fitness; since everybody's
+        // equally unfit, everybody gets an equal share of the wheel
+        let uniform = total_fitness <= 0.0;
+
+        if uniform {
+            total_fitness = population.len() as f32;
+        }
+
+        let fitness = |individual: &I| {
+            if uniform {
+                1.0
+            } else {
+                individual.fitness()
+            }
+        };
+
+        let distance = total_fitness / (count as f32);
+        let start = rng.random::<f32>() * distance;
+
+        let mut selected = Vec::with_capacity(count);
+        let mut individuals = population.iter();
+        let mut individual = individuals.next().unwrap();
+        let mut cumulative_fitness = fitness(individual);
+
+        for pointer in (0..count).map(|n| start + (n as f32) * distance) {
+            while cumulative_fitness < pointer {
+                match individuals.next() {
+                    Some(next) => {
+                        individual = next;
+                        cumulative_fitness += fitness(individual);
+                    }
+
+                    // Can only happen due to floating-point rounding errors
+                    None => break,
+                }
+            }
+
+            selected.push(individual);
+        }
+
+        // Pointers visit individuals in order, so without shuffling the
+        // neighbouring parents would be almost always the same individual
+        selected.shuffle(rng);
+        selected
+    }
+
+    fn selects_many_at_once(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ]
+    }
+
+    fn histogram(individuals: Vec<&TestIndividual>) -> BTreeMap<i32, i32> {
+        individuals
+            .into_iter()
+            .fold(Default::default(), |mut histogram, individual| {
+                *histogram.entry(individual.fitness() as _).or_default() += 1;
+
+                histogram
+            })
+    }
+
+    #[test]
+    fn test() {
+        let method = StochasticUniversalSampling::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+
+        let actual_histogram = histogram(method.select_many(&mut rng, &population, 1000));
+
+        // Unlike the roulette wheel, SUS gives each individual exactly as
+        // many picks as its share of the total fitness
+        let expected_histogram = maplit::btreemap! {
+            // fitness => how many times this fitness has been chosen
+            1 => 100,
+            2 => 200,
+            3 => 300,
+            4 => 400,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn select() {
+        let method = StochasticUniversalSampling::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+
+        let actual_histogram = histogram(
+            (0..1000)
+                .map(|_| method.select(&mut rng, &population))
+                .collect(),
+        );
+
+        let expected_histogram = maplit::btreemap! {
+            // (a single pointer is no different from the roulette wheel)
+            1 => 98,
+            2 => 202,
+            3 => 278,
+            4 => 422,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn with_zero_fitness() {
+        let method = StochasticUniversalSampling::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+            TestIndividual::new(0.0),
+        ];
+
+        let selected = method.select_many(&mut rng, &population, 9);
+
+        // Everybody gets an equal share of the wheel, i.e. exactly the same
+        // number of picks
+        for individual in &population {
+            let picks = selected
+                .iter()
+                .filter(|&&selected| std::ptr::eq(selected, individual))
+                .count();
+
+            assert_eq!(picks, 3);
+        }
+    }
+}
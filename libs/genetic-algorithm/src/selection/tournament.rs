@@ -0,0 +1,147 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+pub struct TournamentSelection {
+    /// How many individuals compete in a single tournament:
+    /// - 1 = no selection pressure at all (uniformly random pick)
+    /// - population size = almost always picks the fittest individual
+    size: usize,
+
+    /// Probability of the fittest contestant winning the tournament:
+    /// - 1.0 = the fittest contestant always wins
+    /// - 0.5 = the fittest contestant wins half of the time; otherwise
+    ///   the runner-up wins with the same chance, and so on
+    winner_chance: f32,
+}
+
+impl TournamentSelection {
+    pub fn new(size: usize) -> Self {
+        Self::with_winner_chance(size, 1.0)
+    }
+
+    pub fn with_winner_chance(size: usize, winner_chance: f32) -> Self {
+        assert!(size > 0);
+        assert!((0.0..=1.0).contains(&winner_chance));
+
+        Self {
+            size,
+            winner_chance,
+        }
+    }
+}
+
+impl Default for TournamentSelection {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl SelectionMethod for TournamentSelection {
+    fn select<'a, I>(&self, rng: &mut dyn Rng, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        let mut contestants: Vec<_> = (0..self.size)
+            .map(|_| population.choose(rng).expect("got an empty population"))
+            .collect();
+
+        // Fittest contestants go first
+        contestants.sort_by(|a, b| b.fitness().total_cmp(&a.fitness()));
+
+        let (last, rest) = contestants.split_last().unwrap();
+
+        rest.iter()
+            .find(|_| rng.random_bool(self.winner_chance as _))
+            .unwrap_or(last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn histogram(method: TournamentSelection, population: &[TestIndividual]) -> BTreeMap<i32, i32> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        (0..1000).map(|_| method.select(&mut rng, population)).fold(
+            Default::default(),
+            |mut histogram, individual| {
+                *histogram.entry(individual.fitness() as _).or_default() += 1;
+
+                histogram
+            },
+        )
+    }
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ]
+    }
+
+    #[test]
+    fn test() {
+        let actual_histogram = histogram(TournamentSelection::new(2), &population());
+
+        let expected_histogram = maplit::btreemap! {
+            // fitness => how many times this fitness has been chosen
+            1 => 64,
+            2 => 191,
+            3 => 288,
+            4 => 457,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn with_winner_chance() {
+        let actual_histogram = histogram(
+            TournamentSelection::with_winner_chance(2, 0.5),
+            &population(),
+        );
+
+        let expected_histogram = maplit::btreemap! {
+            1 => 243,
+            2 => 233,
+            3 => 246,
+            4 => 278,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    fn with_zero_and_negative_fitness() {
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(-1.0),
+            TestIndividual::new(0.0),
+            TestIndividual::new(-3.0),
+        ];
+
+        let actual_histogram = histogram(TournamentSelection::new(2), &population);
+
+        let expected_histogram = maplit::btreemap! {
+            -3 => 65,
+            -1 => 177,
+            0 => 758,
+        };
+
+        assert_eq!(actual_histogram, expected_histogram);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_zero_size() {
+        TournamentSelection::new(0);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::*;
+
+/// Splits the population into species of similar chromosomes, which then
+/// compete mostly among themselves.
+///
+/// Each individual's fitness gets shared with (divided by the size of) its
+/// species, and each species gets offspring in proportion to its average
+/// fitness; parents are picked only from within the same species.
+///
+/// This protects new behaviours: a freshly mutated individual that doesn't
+/// do that well yet has to compete only with its (equally unpolished)
+/// relatives, instead of getting wiped out by a large, already-tuned
+/// species.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Speciation {
+    /// Chromosomes closer than this (see [`compatibility_distance()`])
+    /// belong to the same species:
+    /// - 0.0 = each distinct chromosome is a species of its own
+    /// - the larger, the fewer (and larger) species there are
+    threshold: f32,
+}
+
+impl Speciation {
+    pub fn new(threshold: f32) -> Self {
+        assert!(threshold >= 0.0);
+
+        Self { threshold }
+    }
+
+    /// Returns species as lists of indices into `population`.
+    ///
+    /// Each species is represented by its first member - an individual
+    /// joins the first species whose representative it's compatible with,
+    /// or starts a new species otherwise.
+    pub fn speciate<I>(&self, population: &[I]) -> Vec<Vec<usize>>
+    where
+        I: Individual,
+    {
+        let mut species: Vec<Vec<usize>> = Vec::new();
+
+        for (idx, individual) in population.iter().enumerate() {
+            let compatible = species.iter_mut().find(|members| {
+                let representative = population[members[0]].chromosome();
+
+                compatibility_distance(representative, individual.chromosome()) < self.threshold
+            });
+
+            match compatible {
+                Some(members) => members.push(idx),
+                None => species.push(vec![idx]),
+            }
+        }
+
+        species
+    }
+
+    /// Splits `count` offspring between species in proportion to their
+    /// total (already shared) fitness, using the largest remainder method;
+    /// when there's no fitness at all, offspring are split by size.
+    ///
+    /// Species with negative fitness are treated as having none.
+    pub(crate) fn allot(
+        &self,
+        species_fitness: &[f32],
+        species_sizes: &[usize],
+        count: usize,
+    ) -> Vec<usize> {
+        let species_fitness: Vec<f32> = species_fitness
+            .iter()
+            .map(|&fitness| fitness.max(0.0))
+            .collect();
+
+        let total_fitness: f32 = species_fitness.iter().sum();
+
+        let shares: Vec<f32> = if total_fitness > 0.0 {
+            species_fitness
+                .iter()
+                .map(|fitness| fitness / total_fitness)
+                .collect()
+        } else {
+            let total_size: usize = species_sizes.iter().sum();
+
+            species_sizes
+                .iter()
+                .map(|&size| size as f32 / total_size as f32)
+                .collect()
+        };
+
+        let quotas: Vec<f32> = shares.iter().map(|share| share * count as f32).collect();
+        let mut allotment: Vec<usize> = quotas.iter().map(|quota| quota.floor() as usize).collect();
+
+        let mut by_remainder: Vec<_> = (0..quotas.len()).collect();
+        by_remainder.sort_by(|&a, &b| {
+            (quotas[b] - quotas[b].floor()).total_cmp(&(quotas[a] - quotas[a].floor()))
+        });
+
+        let missing = count - allotment.iter().sum::<usize>();
+
+        for &idx in by_remainder.iter().cycle().take(missing) {
+            allotment[idx] += 1;
+        }
+
+        allotment
+    }
+}
+
+/// Returns how different two chromosomes are: the mean absolute difference
+/// between their genes.
+pub fn compatibility_distance(a: &Chromosome, b: &Chromosome) -> f32 {
+    assert_eq!(a.len(), b.len());
+
+    if a.is_empty() {
+        return 0.0;
+    }
+
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| (a - b).abs())
+        .sum::<f32>()
+        / (a.len() as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn individual(genes: &[f32]) -> TestIndividual {
+        TestIndividual::create(genes.iter().cloned().collect())
+    }
+
+    #[test]
+    fn test_compatibility_distance() {
+        let a: Chromosome = vec![0.0, 1.0, 2.0].into_iter().collect();
+        let b: Chromosome = vec![1.0, 1.0, 0.0].into_iter().collect();
+
+        assert_eq!(compatibility_distance(&a, &b), 1.0);
+        assert_eq!(compatibility_distance(&a, &a), 0.0);
+    }
+
+    #[test]
+    fn speciate() {
+        let population = vec![
+            individual(&[0.0, 0.0]),
+            individual(&[5.0, 5.0]),
+            individual(&[0.2, 0.0]),
+            individual(&[5.0, 4.8]),
+            individual(&[-3.0, 0.0]),
+        ];
+
+        let species = Speciation::new(0.5).speciate(&population);
+
+        assert_eq!(species, vec![vec![0, 2], vec![1, 3], vec![4]]);
+    }
+
+    mod allot {
+        use super::*;
+
+        #[test]
+        fn in_proportion_to_fitness() {
+            let allotment = Speciation::new(1.0).allot(&[6.0, 3.0, 1.0], &[1, 1, 1], 10);
+
+            assert_eq!(allotment, vec![6, 3, 1]);
+        }
+
+        #[test]
+        fn with_remainders() {
+            let allotment = Speciation::new(1.0).allot(&[1.0, 1.0, 1.0], &[1, 1, 1], 10);
+
+            assert_eq!(allotment.iter().sum::<usize>(), 10);
+            assert!(allotment.iter().all(|&count| count == 3 || count == 4));
+        }
+
+        #[test]
+        fn without_any_fitness() {
+            let allotment = Speciation::new(1.0).allot(&[0.0, 0.0], &[3, 1], 8);
+
+            assert_eq!(allotment, vec![6, 2]);
+        }
+
+        #[test]
+        fn with_negative_fitness() {
+            let allotment = Speciation::new(1.0).allot(&[3.0, -1.0], &[1, 1], 10);
+
+            assert_eq!(allotment, vec![10, 0]);
+        }
+
+        #[test]
+        fn with_only_negative_fitness() {
+            let allotment = Speciation::new(1.0).allot(&[-3.0, -1.0], &[3, 1], 8);
+
+            assert_eq!(allotment, vec![6, 2]);
+        }
+    }
+}
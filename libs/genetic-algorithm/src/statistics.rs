@@ -1,10 +1,17 @@
 use crate::*;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Statistics {
     min_fitness: f32,
     max_fitness: f32,
     avg_fitness: f32,
+    median_fitness: f32,
+    std_dev_fitness: f32,
+    q1_fitness: f32,
+    q3_fitness: f32,
+    gene_diversity: f32,
+    species_sizes: Vec<usize>,
 }
 
 impl Statistics {
@@ -14,22 +21,25 @@ impl Statistics {
     {
         assert!(!population.is_empty());
 
-        let mut min_fitness = population[0].fitness();
-        let mut max_fitness = min_fitness;
-        let mut sum_fitness = 0.0;
+        let mut fitness: Vec<_> = population
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect();
 
-        for individual in population {
-            let fitness = individual.fitness();
+        fitness.sort_by(f32::total_cmp);
 
-            min_fitness = min_fitness.min(fitness);
-            max_fitness = max_fitness.max(fitness);
-            sum_fitness += fitness;
-        }
+        let avg_fitness = mean(&fitness);
 
         Self {
-            min_fitness,
-            max_fitness,
-            avg_fitness: sum_fitness / (population.len() as f32),
+            min_fitness: fitness[0],
+            max_fitness: fitness[fitness.len() - 1],
+            avg_fitness,
+            median_fitness: percentile(&fitness, 0.5),
+            std_dev_fitness: variance(&fitness, avg_fitness).sqrt(),
+            q1_fitness: percentile(&fitness, 0.25),
+            q3_fitness: percentile(&fitness, 0.75),
+            gene_diversity: gene_diversity(population),
+            species_sizes: vec![population.len()],
         }
     }
 
@@ -44,6 +54,108 @@ impl Statistics {
     pub fn avg_fitness(&self) -> f32 {
         self.avg_fitness
     }
+
+    pub fn median_fitness(&self) -> f32 {
+        self.median_fitness
+    }
+
+    /// Standard deviation of fitness across the population.
+    pub fn std_dev_fitness(&self) -> f32 {
+        self.std_dev_fitness
+    }
+
+    /// First quartile (25th percentile) of fitness.
+    pub fn q1_fitness(&self) -> f32 {
+        self.q1_fitness
+    }
+
+    /// Third quartile (75th percentile) of fitness.
+    pub fn q3_fitness(&self) -> f32 {
+        self.q3_fitness
+    }
+
+    /// How different the individuals are from each other, measured as the
+    /// variance of each gene across the population, averaged over all the
+    /// genes:
+    ///
+    /// - 0.0 = all individuals have the very same genes (i.e. the population
+    ///   has converged, and only mutations can bring anything new),
+    /// - the larger, the more varied the population is.
+    ///
+    /// Together with fitness this tells premature convergence (low
+    /// diversity, flat fitness) apart from stagnation (high diversity, flat
+    /// fitness).
+    pub fn gene_diversity(&self) -> f32 {
+        self.gene_diversity
+    }
+
+    /// Returns the same statistics, but with the population split into
+    /// species of given sizes (see [`Speciation`]).
+    pub fn with_species_sizes(mut self, species_sizes: Vec<usize>) -> Self {
+        assert_eq!(
+            species_sizes.iter().sum::<usize>(),
+            self.species_sizes.iter().sum::<usize>()
+        );
+
+        self.species_sizes = species_sizes;
+        self
+    }
+
+    /// Number of species in the population; without speciation the entire
+    /// population is a single species.
+    pub fn species_count(&self) -> usize {
+        self.species_sizes.len()
+    }
+
+    pub fn species_sizes(&self) -> &[usize] {
+        &self.species_sizes
+    }
+}
+
+fn mean(values: &[f32]) -> f32 {
+    values.iter().sum::<f32>() / (values.len() as f32)
+}
+
+fn variance(values: &[f32], mean: f32) -> f32 {
+    values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f32>()
+        / (values.len() as f32)
+}
+
+/// Returns given percentile of sorted values, interpolating linearly
+/// between the closest ones.
+fn percentile(sorted: &[f32], p: f32) -> f32 {
+    let rank = p * (sorted.len() - 1) as f32;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f32)
+}
+
+fn gene_diversity<I>(population: &[I]) -> f32
+where
+    I: Individual,
+{
+    let genes = population[0].chromosome().len();
+
+    if genes == 0 {
+        return 0.0;
+    }
+
+    let total_variance: f32 = (0..genes)
+        .map(|idx| {
+            let values: Vec<_> = population
+                .iter()
+                .map(|individual| individual.chromosome()[idx])
+                .collect();
+
+            variance(&values, mean(&values))
+        })
+        .sum();
+
+    total_variance / (genes as f32)
 }
 
 #[cfg(test)]
@@ -91,4 +203,78 @@ mod test {
 
         assert_eq!(statistics.avg_fitness(), 3.5);
     }
+
+    #[test]
+    fn test_median_fitness() {
+        let statistics = Statistics::new(&population());
+
+        assert_eq!(statistics.median_fitness(), 3.5);
+    }
+
+    #[test]
+    fn test_std_dev_fitness() {
+        let statistics = Statistics::new(&population());
+
+        assert_eq!(statistics.std_dev_fitness(), 2.5);
+    }
+
+    #[test]
+    fn test_quartiles() {
+        let statistics = Statistics::new(&population());
+
+        assert_eq!(statistics.q1_fitness(), 2.25);
+        assert_eq!(statistics.q3_fitness(), 4.75);
+    }
+
+    #[test]
+    fn test_gene_diversity() {
+        let statistics = Statistics::new(&population());
+
+        approx::assert_relative_eq!(statistics.gene_diversity(), 3.125 / 3.0);
+    }
+
+    #[test]
+    fn test_gene_diversity_of_a_converged_population() {
+        let population = vec![individual(&[1.0, 2.0, 4.0]); 3];
+        let statistics = Statistics::new(&population);
+
+        assert_eq!(statistics.gene_diversity(), 0.0);
+    }
+
+    #[test]
+    fn test_species() {
+        let statistics = Statistics::new(&population());
+
+        assert_eq!(statistics.species_count(), 1);
+        assert_eq!(statistics.species_sizes(), &[4]);
+
+        let statistics = statistics.with_species_sizes(vec![1, 3]);
+
+        assert_eq!(statistics.species_count(), 2);
+        assert_eq!(statistics.species_sizes(), &[1, 3]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let statistics = Statistics::new(&population()).with_species_sizes(vec![1, 3]);
+
+        let assert_same = |actual: Statistics| {
+            approx::assert_relative_eq!(actual.min_fitness(), statistics.min_fitness());
+            approx::assert_relative_eq!(actual.max_fitness(), statistics.max_fitness());
+            approx::assert_relative_eq!(actual.avg_fitness(), statistics.avg_fitness());
+            approx::assert_relative_eq!(actual.median_fitness(), statistics.median_fitness());
+            approx::assert_relative_eq!(actual.std_dev_fitness(), statistics.std_dev_fitness());
+            approx::assert_relative_eq!(actual.q1_fitness(), statistics.q1_fitness());
+            approx::assert_relative_eq!(actual.q3_fitness(), statistics.q3_fitness());
+            approx::assert_relative_eq!(actual.gene_diversity(), statistics.gene_diversity());
+            assert_eq!(actual.species_sizes(), statistics.species_sizes());
+        };
+
+        let json = serde_json::to_string(&statistics).unwrap();
+        assert_same(serde_json::from_str(&json).unwrap());
+
+        let bytes = bincode::serialize(&statistics).unwrap();
+        assert_same(bincode::deserialize(&bytes).unwrap());
+    }
 }
@@ -0,0 +1,116 @@
+/// Function applied to each neuron's output (i.e. to its bias plus the
+/// weighted sum of its inputs).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Activation {
+    /// `x`
+    Identity,
+
+    /// `max(x, 0)`
+    #[default]
+    Relu,
+
+    /// `x` for positive `x`, `slope * x` otherwise
+    LeakyRelu { slope: f32 },
+
+    /// `1 / (1 + e^-x)`, i.e. `0.0..1.0`
+    Sigmoid,
+
+    /// `tanh(x)`, i.e. `-1.0..1.0`
+    Tanh,
+
+    /// `x / (1 + |x|)`, i.e. `-1.0..1.0` - like tanh, but cheaper and with
+    /// gentler tails
+    Softsign,
+
+    /// `1` for positive `x`, `0` otherwise
+    Step,
+}
+
+impl Activation {
+    pub fn apply(self, x: f32) -> f32 {
+        match self {
+            Self::Identity => x,
+            Self::Relu => x.max(0.0),
+            Self::LeakyRelu { slope } => {
+                if x > 0.0 {
+                    x
+                } else {
+                    slope * x
+                }
+            }
+            Self::Sigmoid => 1.0 / (1.0 + (-x).exp()),
+            Self::Tanh => x.tanh(),
+            Self::Softsign => x / (1.0 + x.abs()),
+            Self::Step => {
+                if x > 0.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn apply(activation: Activation) -> [f32; 3] {
+        [-2.0, 0.0, 2.0].map(|x| activation.apply(x))
+    }
+
+    #[test]
+    fn identity() {
+        assert_eq!(apply(Activation::Identity), [-2.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn relu() {
+        assert_eq!(apply(Activation::Relu), [0.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn leaky_relu() {
+        assert_eq!(
+            apply(Activation::LeakyRelu { slope: 0.1 }),
+            [-0.2, 0.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn sigmoid() {
+        assert_relative_eq!(
+            apply(Activation::Sigmoid).as_slice(),
+            [0.11920292, 0.5, 0.8807971].as_slice()
+        );
+    }
+
+    #[test]
+    fn tanh() {
+        assert_relative_eq!(
+            apply(Activation::Tanh).as_slice(),
+            [-0.9640276, 0.0, 0.9640276].as_slice()
+        );
+    }
+
+    #[test]
+    fn softsign() {
+        assert_relative_eq!(
+            apply(Activation::Softsign).as_slice(),
+            [-2.0 / 3.0, 0.0, 2.0 / 3.0].as_slice()
+        );
+    }
+
+    #[test]
+    fn step() {
+        assert_eq!(apply(Activation::Step), [0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn default_is_relu() {
+        assert_eq!(Activation::default(), Activation::Relu);
+    }
+}
@@ -0,0 +1,40 @@
+/// Memory of a recurrent network - each recurrent layer's outputs from the
+/// previous step (see [`Network::step()`](crate::Network::step)).
+///
+/// Kept outside of the network, so that a single network can drive many
+/// independent agents, each with its own memory.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HiddenState {
+    /// Previous outputs of each layer; empty for non-recurrent layers
+    pub(crate) layers: Vec<Vec<f32>>,
+}
+
+impl HiddenState {
+    /// Forgets everything, as if the network has just been created.
+    pub fn reset(&mut self) {
+        for layer in &mut self.layers {
+            layer.fill(0.0);
+        }
+    }
+
+    pub fn layers(&self) -> &[Vec<f32>] {
+        &self.layers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset() {
+        let mut state = HiddenState {
+            layers: vec![vec![1.0, 2.0], vec![]],
+        };
+
+        state.reset();
+
+        assert_eq!(state.layers(), [vec![0.0, 0.0], vec![]]);
+    }
+}
@@ -1,8 +1,18 @@
 use crate::*;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Layer {
     pub neurons: Vec<Neuron>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub activation: Activation,
+
+    /// Whether this layer's previous outputs get fed back into it; if so,
+    /// each neuron's weights are followed by one extra weight per neuron of
+    /// this layer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recurrent: bool,
 }
 
 impl Layer {
@@ -13,7 +23,25 @@ impl Layer {
             .iter()
             .all(|neuron| neuron.weights.len() == neurons[0].weights.len()));
 
-        Self { neurons }
+        Self {
+            neurons,
+            activation: Activation::default(),
+            recurrent: false,
+        }
+    }
+
+    pub fn with_activation(mut self, activation: Activation) -> Self {
+        self.activation = activation;
+        self
+    }
+
+    pub fn with_recurrent(mut self, recurrent: bool) -> Self {
+        if recurrent {
+            assert!(self.neurons[0].weights.len() > self.neurons.len());
+        }
+
+        self.recurrent = recurrent;
+        self
     }
 
     pub fn random(rng: &mut dyn Rng, input_neurons: usize, output_neurons: usize) -> Self {
@@ -24,13 +52,39 @@ impl Layer {
         Self::new(neurons)
     }
 
+    /// Propagates inputs through this layer; recurrent layers behave as if
+    /// their previous outputs were all zeros.
     pub fn propagate(&self, inputs: Vec<f32>) -> Vec<f32> {
+        if self.recurrent {
+            return self.step(inputs, &mut vec![0.0; self.neurons.len()]);
+        }
+
         self.neurons
             .iter()
-            .map(|neuron| neuron.propagate(&inputs))
+            .map(|neuron| neuron.propagate(&inputs, self.activation))
             .collect()
     }
 
+    /// Ditto, but feeding `state` (this layer's previous outputs) back into
+    /// a recurrent layer, and then updating it; non-recurrent layers ignore
+    /// `state`.
+    pub fn step(&self, mut inputs: Vec<f32>, state: &mut Vec<f32>) -> Vec<f32> {
+        if !self.recurrent {
+            return self.propagate(inputs);
+        }
+
+        inputs.extend_from_slice(state);
+
+        let outputs: Vec<_> = self
+            .neurons
+            .iter()
+            .map(|neuron| neuron.propagate(&inputs, self.activation))
+            .collect();
+
+        state.clone_from(&outputs);
+        outputs
+    }
+
     pub fn from_weights(
         input_size: usize,
         output_size: usize,
@@ -77,4 +131,40 @@ mod tests {
             layer.propagate(vec![32.1, 13.5, 12.4, 53.3, 129.3])
         );
     }
+
+    #[test]
+    fn propagate_applies_the_activation() {
+        let layer = Layer::new(vec![
+            Neuron::new(0.0, vec![1.0]),
+            Neuron::new(0.0, vec![-1.0]),
+        ]);
+
+        assert_eq!(vec![2.0, 0.0], layer.propagate(vec![2.0]));
+
+        let layer = layer.with_activation(Activation::Identity);
+
+        assert_eq!(vec![2.0, -2.0], layer.propagate(vec![2.0]));
+    }
+
+    #[test]
+    fn recurrent_layers_remember_their_previous_outputs() {
+        // Accumulates its input - weight of 1.0 for the input, and 1.0 for
+        // the neuron's own previous output
+        let layer = Layer::new(vec![Neuron::new(0.0, vec![1.0, 1.0])]).with_recurrent(true);
+        let mut state = vec![0.0];
+
+        assert_eq!(vec![1.0], layer.step(vec![1.0], &mut state));
+        assert_eq!(vec![1.0], layer.step(vec![0.0], &mut state));
+        assert_eq!(vec![3.0], layer.step(vec![2.0], &mut state));
+        assert_eq!(vec![3.0], state);
+
+        // Without the state, it's got no memory
+        assert_eq!(vec![0.0], layer.propagate(vec![0.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn recurrent_layers_need_recurrent_weights() {
+        Layer::new(vec![Neuron::new(0.0, vec![1.0])]).with_recurrent(true);
+    }
 }
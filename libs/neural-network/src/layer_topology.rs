@@ -1,16 +1,77 @@
-#[derive(Clone, Copy, Debug)]
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LayerTopology {
     pub neurons: usize,
+
+    /// Activation of this layer's neurons; ignored for the input layer,
+    /// which has no neurons of its own.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub activation: Activation,
+
+    /// Whether this layer's previous outputs get fed back into it,
+    /// together with its inputs (see [`Network::step()`]); ignored for the
+    /// input layer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recurrent: bool,
+}
+
+impl LayerTopology {
+    pub fn new(neurons: usize) -> Self {
+        Self {
+            neurons,
+            activation: Activation::default(),
+            recurrent: false,
+        }
+    }
+
+    pub fn with_activation(mut self, activation: Activation) -> Self {
+        self.activation = activation;
+        self
+    }
+
+    pub fn with_recurrent(mut self, recurrent: bool) -> Self {
+        self.recurrent = recurrent;
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::LayerTopology;
+    use crate::*;
 
     #[test]
     fn test() {
-        let layer_topology = LayerTopology { neurons: 3 };
+        let layer_topology = LayerTopology {
+            neurons: 3,
+            ..Default::default()
+        };
 
         assert_eq!(3, layer_topology.neurons);
+        assert_eq!(Activation::Relu, layer_topology.activation);
+    }
+
+    #[test]
+    fn new() {
+        let layer_topology = LayerTopology::new(3);
+
+        assert_eq!(3, layer_topology.neurons);
+        assert_eq!(Activation::Relu, layer_topology.activation);
+        assert!(!layer_topology.recurrent);
+    }
+
+    #[test]
+    fn with_activation() {
+        let layer_topology = LayerTopology::new(3).with_activation(Activation::Tanh);
+
+        assert_eq!(Activation::Tanh, layer_topology.activation);
+    }
+
+    #[test]
+    fn with_recurrent() {
+        let layer_topology = LayerTopology::new(3).with_recurrent(true);
+
+        assert!(layer_topology.recurrent);
     }
 }
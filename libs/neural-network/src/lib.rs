@@ -1,13 +1,17 @@
-pub use self::layer_topology::*;
+pub use self::{activation::*, hidden_state::*, layer_topology::*};
 
 use self::{layer::*, neuron::*};
 use rand::Rng;
+use std::ops::Range;
 
+mod activation;
+mod hidden_state;
 mod layer;
 mod layer_topology;
 mod neuron;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Network {
     layers: Vec<Layer>,
 }
@@ -20,18 +24,52 @@ impl Network {
     pub fn random(rng: &mut dyn Rng, layers: &[LayerTopology]) -> Self {
         let layers = layers
             .windows(2)
-            .map(|layers| Layer::random(rng, layers[0].neurons, layers[1].neurons))
+            .map(|layers| {
+                Layer::random(rng, neuron_inputs(layers), layers[1].neurons)
+                    .with_activation(layers[1].activation)
+                    .with_recurrent(layers[1].recurrent)
+            })
             .collect();
 
         Self { layers }
     }
 
+    /// Propagates inputs through the network; recurrent layers behave as if
+    /// the network has just been created (see [`Self::step()`]).
     pub fn propagate(&self, inputs: Vec<f32>) -> Vec<f32> {
         self.layers
             .iter()
             .fold(inputs, |inputs, layer| layer.propagate(inputs))
     }
 
+    /// Ditto, but remembering the recurrent layers' outputs in `state` (see
+    /// [`Self::hidden_state()`]) for the next step.
+    pub fn step(&self, inputs: Vec<f32>, state: &mut HiddenState) -> Vec<f32> {
+        assert_eq!(state.layers.len(), self.layers.len());
+
+        self.layers
+            .iter()
+            .zip(&mut state.layers)
+            .fold(inputs, |inputs, (layer, state)| layer.step(inputs, state))
+    }
+
+    /// Returns memory of a network that has just been created.
+    pub fn hidden_state(&self) -> HiddenState {
+        HiddenState {
+            layers: self
+                .layers
+                .iter()
+                .map(|layer| {
+                    if layer.recurrent {
+                        vec![0.0; layer.neurons.len()]
+                    } else {
+                        Vec::new()
+                    }
+                })
+                .collect(),
+        }
+    }
+
     pub fn weights(&self) -> impl Iterator<Item = f32> + '_ {
         use std::iter::once;
 
@@ -49,7 +87,11 @@ impl Network {
 
         let layers = layers
             .windows(2)
-            .map(|layers| Layer::from_weights(layers[0].neurons, layers[1].neurons, &mut weights))
+            .map(|layers| {
+                Layer::from_weights(neuron_inputs(layers), layers[1].neurons, &mut weights)
+                    .with_activation(layers[1].activation)
+                    .with_recurrent(layers[1].recurrent)
+            })
             .collect();
 
         if weights.next().is_some() {
@@ -58,6 +100,56 @@ impl Network {
 
         Self::new(layers)
     }
+
+    /// Returns which of the `.weights()` belong to which neuron - e.g. for
+    /// a network with 3 inputs and 2 outputs, that's `[0..4, 4..8]` (each
+    /// neuron has one bias and three weights).
+    ///
+    /// Neurons of recurrent layers have one extra weight per neuron of their
+    /// layer, right after the usual ones.
+    ///
+    /// Useful for genetic operators that want to treat a neuron as a
+    /// whole, instead of as a bunch of unrelated numbers.
+    pub fn neuron_boundaries(layers: &[LayerTopology]) -> Vec<Range<usize>> {
+        let mut start = 0;
+
+        layers
+            .windows(2)
+            .flat_map(|layers| {
+                let neuron_len = 1 + neuron_inputs(layers);
+
+                (0..layers[1].neurons).map(move |_| neuron_len)
+            })
+            .map(|len| {
+                start += len;
+                (start - len)..start
+            })
+            .collect()
+    }
+
+    /// Ditto, but for layers.
+    pub fn layer_boundaries(layers: &[LayerTopology]) -> Vec<Range<usize>> {
+        let mut start = 0;
+
+        layers
+            .windows(2)
+            .map(|layers| (1 + neuron_inputs(layers)) * layers[1].neurons)
+            .map(|len| {
+                start += len;
+                (start - len)..start
+            })
+            .collect()
+    }
+}
+
+/// Returns how many inputs each neuron of `layers[1]` has (given the
+/// previous layer, `layers[0]`).
+fn neuron_inputs(layers: &[LayerTopology]) -> usize {
+    if layers[1].recurrent {
+        layers[0].neurons + layers[1].neurons
+    } else {
+        layers[0].neurons
+    }
 }
 
 #[cfg(test)]
@@ -98,11 +190,11 @@ mod test {
             };
 
             // Ensures `.ax()` (our LeLU) works:
-            assert_relative_eq!(neuron.propagate(&[-10.0, -10.0]), 0.0,);
+            assert_relative_eq!(neuron.propagate(&[-10.0, -10.0], Activation::Relu), 0.0,);
 
             // `0.5` and `1.0` chosen by a fair dice roll:
             assert_relative_eq!(
-                neuron.propagate(&[0.5, 1.0]),
+                neuron.propagate(&[0.5, 1.0], Activation::Relu),
                 (-0.3 * 0.5) + (0.8 * 1.0) + 0.5,
             );
 
@@ -115,7 +207,7 @@ mod test {
 
         #[test]
         fn test() {
-            let layers = &[LayerTopology { neurons: 3 }, LayerTopology { neurons: 2 }];
+            let layers = &[LayerTopology::new(3), LayerTopology::new(2)];
 
             let weights = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8];
 
@@ -124,7 +216,177 @@ mod test {
 
             approx::assert_relative_eq!(actual.as_slice(), weights.as_slice());
         }
+
+        #[test]
+        fn uses_each_layers_activation() {
+            let layers = &[
+                LayerTopology::new(1),
+                LayerTopology::new(2).with_activation(Activation::Identity),
+                LayerTopology::new(1).with_activation(Activation::Tanh),
+            ];
+
+            // 1 -> [x, -x] -> tanh(x - x + 0.5)
+            let network = Network::from_weights(layers, vec![0.0, 1.0, 0.0, -1.0, 0.5, 1.0, 1.0]);
+
+            approx::assert_relative_eq!(network.propagate(vec![3.0])[0], 0.5f32.tanh());
+
+            // (with the default ReLU, the -x would be lost)
+            let network = Network::from_weights(
+                &[
+                    LayerTopology::new(1),
+                    LayerTopology::new(2),
+                    LayerTopology::new(1),
+                ],
+                vec![0.0, 1.0, 0.0, -1.0, 0.5, 1.0, 1.0],
+            );
+
+            approx::assert_relative_eq!(network.propagate(vec![3.0])[0], 3.5);
+        }
+    }
+
+    mod random_with_activations {
+        use super::*;
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        #[test]
+        fn test() {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+            let network = Network::random(
+                &mut rng,
+                &[
+                    LayerTopology::new(3),
+                    LayerTopology::new(4).with_activation(Activation::Sigmoid),
+                    LayerTopology::new(2),
+                ],
+            );
+
+            assert_eq!(network.layers[0].activation, Activation::Sigmoid);
+            assert_eq!(network.layers[1].activation, Activation::Relu);
+        }
+    }
+
+    mod boundaries {
+        use super::*;
+
+        fn layers() -> [LayerTopology; 3] {
+            [
+                LayerTopology::new(3),
+                LayerTopology::new(2),
+                LayerTopology::new(1),
+            ]
+        }
+
+        #[test]
+        fn neuron_boundaries() {
+            assert_eq!(
+                Network::neuron_boundaries(&layers()),
+                vec![0..4, 4..8, 8..11],
+            );
+        }
+
+        #[test]
+        fn layer_boundaries() {
+            assert_eq!(Network::layer_boundaries(&layers()), vec![0..8, 8..11]);
+        }
+
+        #[test]
+        fn boundaries_cover_all_the_weights() {
+            let network = Network::from_weights(&layers(), (0..11).map(|n| n as f32));
+
+            assert_eq!(
+                Network::neuron_boundaries(&layers()).last().unwrap().end,
+                network.weights().count(),
+            );
+
+            assert_eq!(
+                Network::layer_boundaries(&layers()).last().unwrap().end,
+                network.weights().count(),
+            );
+        }
     }
+
+    mod recurrent {
+        use super::*;
+
+        fn layers() -> [LayerTopology; 3] {
+            [
+                LayerTopology::new(1),
+                LayerTopology::new(2)
+                    .with_activation(Activation::Identity)
+                    .with_recurrent(true),
+                LayerTopology::new(1).with_activation(Activation::Identity),
+            ]
+        }
+
+        /// Returns a network with a hidden layer of two neurons - one
+        /// passing the input through, and one accumulating it - whose
+        /// outputs get summed up.
+        pub(super) fn network() -> Network {
+            Network::from_weights(
+                &layers(),
+                vec![
+                    0.0, 1.0, 0.0, 0.0, // pass-through
+                    0.0, 1.0, 0.0, 1.0, // accumulator
+                    0.0, 1.0, 1.0, // sum
+                ],
+            )
+        }
+
+        #[test]
+        fn step() {
+            let network = network();
+            let mut state = network.hidden_state();
+
+            assert_eq!(network.step(vec![1.0], &mut state), vec![2.0]);
+            assert_eq!(network.step(vec![2.0], &mut state), vec![5.0]);
+            assert_eq!(network.step(vec![0.0], &mut state), vec![3.0]);
+
+            assert_eq!(state.layers(), [vec![0.0, 3.0], vec![]]);
+
+            state.reset();
+
+            assert_eq!(network.step(vec![1.0], &mut state), vec![2.0]);
+        }
+
+        #[test]
+        fn propagate_starts_from_scratch() {
+            let network = network();
+
+            assert_eq!(network.propagate(vec![1.0]), vec![2.0]);
+            assert_eq!(network.propagate(vec![1.0]), vec![2.0]);
+        }
+
+        #[test]
+        fn recurrent_weights_are_part_of_the_weights() {
+            let weights: Vec<_> = network().weights().collect();
+
+            assert_eq!(weights.len(), 11);
+            assert_eq!(
+                Network::neuron_boundaries(&layers()),
+                vec![0..4, 4..8, 8..11]
+            );
+            assert_eq!(Network::layer_boundaries(&layers()), vec![0..8, 8..11]);
+
+            let rebuilt = Network::from_weights(&layers(), weights.clone());
+
+            assert_eq!(rebuilt.weights().collect::<Vec<_>>(), weights);
+        }
+
+        #[test]
+        fn random() {
+            use rand::SeedableRng;
+            use rand_chacha::ChaCha8Rng;
+
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let network = Network::random(&mut rng, &layers());
+
+            assert_eq!(network.weights().count(), 11);
+            assert_eq!(network.hidden_state().layers(), [vec![0.0, 0.0], vec![]]);
+        }
+    }
+
     mod weights {
         use super::*;
 
@@ -141,4 +403,82 @@ mod test {
             approx::assert_relative_eq!(actual.as_slice(), expected.as_slice(),);
         }
     }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+
+        fn network() -> Network {
+            Network::from_weights(
+                &[LayerTopology::new(3), LayerTopology::new(2)],
+                vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8],
+            )
+        }
+
+        fn assert_same(actual: Network) {
+            let actual: Vec<_> = actual.weights().collect();
+            let expected: Vec<_> = network().weights().collect();
+
+            approx::assert_relative_eq!(actual.as_slice(), expected.as_slice());
+        }
+
+        #[test]
+        fn json() {
+            let json = serde_json::to_string(&network()).unwrap();
+
+            assert_eq!(
+                json,
+                r#"{"layers":[{"neurons":[{"bias":0.1,"weights":[0.2,0.3,0.4]},{"bias":0.5,"weights":[0.6,0.7,0.8]}],"activation":"Relu","recurrent":false}]}"#
+            );
+
+            assert_same(serde_json::from_str(&json).unwrap());
+        }
+
+        #[test]
+        fn binary() {
+            let bytes = bincode::serialize(&network()).unwrap();
+
+            assert_same(bincode::deserialize(&bytes).unwrap());
+        }
+
+        #[test]
+        fn layer_topology() {
+            let json = serde_json::to_string(&[
+                LayerTopology::new(3),
+                LayerTopology::new(2).with_activation(Activation::LeakyRelu { slope: 0.5 }),
+            ])
+            .unwrap();
+
+            assert_eq!(
+                json,
+                r#"[{"neurons":3,"activation":"Relu","recurrent":false},{"neurons":2,"activation":{"LeakyRelu":{"slope":0.5}},"recurrent":false}]"#
+            );
+
+            let topology: Vec<LayerTopology> = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(topology[1].neurons, 2);
+            assert_eq!(topology[1].activation, Activation::LeakyRelu { slope: 0.5 });
+        }
+
+        #[test]
+        fn layer_topology_without_activation() {
+            let topology: Vec<LayerTopology> = serde_json::from_str(r#"[{"neurons":3}]"#).unwrap();
+
+            assert_eq!(topology[0].activation, Activation::Relu);
+            assert!(!topology[0].recurrent);
+        }
+
+        #[test]
+        fn hidden_state() {
+            let network = recurrent::network();
+            let mut state = network.hidden_state();
+
+            network.step(vec![1.0], &mut state);
+
+            let json = serde_json::to_string(&state).unwrap();
+
+            assert_eq!(json, r#"{"layers":[[1.0,1.0],[]]}"#);
+            assert_eq!(serde_json::from_str::<HiddenState>(&json).unwrap(), state);
+        }
+    }
 }
@@ -1,6 +1,8 @@
-use rand::{Rng, RngExt};
+use crate::*;
+use rand::RngExt;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Neuron {
     pub bias: f32,
     pub weights: Vec<f32>,
@@ -23,14 +25,14 @@ impl Neuron {
         Self::new(bias, weights)
     }
 
-    pub fn propagate(&self, inputs: &[f32]) -> f32 {
+    pub fn propagate(&self, inputs: &[f32], activation: Activation) -> f32 {
         let output = inputs
             .iter()
             .zip(&self.weights)
             .map(|(input, weight)| input * weight)
             .sum::<f32>();
 
-        (self.bias + output).max(0.0)
+        activation.apply(self.bias + output)
     }
 
     pub fn from_weights(output_neurons: usize, weights: &mut dyn Iterator<Item = f32>) -> Self {
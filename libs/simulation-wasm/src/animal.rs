@@ -17,6 +17,16 @@ impl From<&sim::Animal> for Animal {
     }
 }
 
+impl From<&sim::AnimalFrame> for Animal {
+    fn from(animal: &sim::AnimalFrame) -> Self {
+        Self {
+            x: animal.position().x,
+            y: animal.position().y,
+            rotation: animal.rotation().angle(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,6 +15,15 @@ impl From<&sim::Food> for Food {
     }
 }
 
+impl From<&sim::FoodFrame> for Food {
+    fn from(food: &sim::FoodFrame) -> Self {
+        Self {
+            x: food.position().x,
+            y: food.position().y,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
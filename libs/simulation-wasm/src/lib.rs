@@ -1,11 +1,11 @@
-pub use self::{animal::*, food::*, world::*};
+pub use self::{animal::*, food::*, player::*, world::*};
 use lib_simulation as sim;
-use rand::rngs::ThreadRng;
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
 mod animal;
 mod food;
+mod player;
 mod world;
 
 #[derive(Debug, Clone, Serialize)]
@@ -14,14 +14,28 @@ pub struct GenerationStats {
     pub min_fitness: f32,
     pub max_fitness: f32,
     pub avg_fitness: f32,
+    pub median_fitness: f32,
+    pub std_dev_fitness: f32,
+    pub q1_fitness: f32,
+    pub q3_fitness: f32,
+    pub gene_diversity: f32,
+    pub species_count: usize,
+    pub species_sizes: Vec<usize>,
+
+    /// Objectives of birds forming the Pareto front; empty unless birds are
+    /// evolved for more than one objective.
+    pub pareto_front: Vec<Vec<f32>>,
 }
 
 #[wasm_bindgen]
 pub struct Simulation {
-    rng: ThreadRng,
     sim: sim::Simulation,
+    recorder: Option<sim::Recorder>,
 }
 
+// `JsValue::from_serde()` is deprecated in favour of `serde-wasm-bindgen`,
+// but it's still perfectly fine for the tiny payloads we send over.
+#[allow(deprecated)]
 #[wasm_bindgen]
 impl Simulation {
     #[wasm_bindgen(constructor)]
@@ -31,7 +45,9 @@ impl Simulation {
         mutation_chance: Option<f32>,
         mutation_coeff: Option<f32>,
         max_speed: Option<f32>,
-    ) -> Self {
+        elite_count: Option<usize>,
+        seed: Option<u64>,
+    ) -> Result<Simulation, JsError> {
         let defaults = sim::Config::default();
 
         let config = sim::Config {
@@ -44,12 +60,23 @@ impl Simulation {
                 .unwrap_or(defaults.mutation_coeff)
                 .clamp(0.0, 1.0),
             max_speed: max_speed.unwrap_or(defaults.max_speed).max(0.0),
+            elitism: elite_count
+                .map(sim::Elitism::Count)
+                .unwrap_or(defaults.elitism),
+            seed,
+            ..defaults
         };
 
-        let mut rng = rand::rng();
-        let sim = sim::Simulation::random_with_config(&mut rng, config);
+        config.validate()?;
+
+        // (the simulation owns its random number generator, so that the
+        // same seed yields the same run as natively)
+        let sim = sim::Simulation::seeded(config);
 
-        Self { rng, sim }
+        Ok(Self {
+            sim,
+            recorder: None,
+        })
     }
 
     pub fn world(&self) -> JsValue {
@@ -62,37 +89,129 @@ impl Simulation {
     /// Returns structured statistics whenever a generation has just
     /// finished (i.e. the population evolved), or `None` otherwise.
     pub fn step(&mut self) -> JsValue {
-        match self.sim.step(&mut self.rng) {
+        match self.step_recorded() {
             Some(stats) => JsValue::from_serde(&self.generation_stats(&stats)).unwrap(),
             None => JsValue::NULL,
         }
     }
 
     pub fn train(&mut self) -> JsValue {
-        let stats = self.sim.train(&mut self.rng);
+        let stats = if self.recorder.is_some() {
+            // (going step by step, so that the recording doesn't skip the
+            // rest of the generation)
+            loop {
+                if let Some(stats) = self.step_recorded() {
+                    break stats;
+                }
+            }
+        } else {
+            self.sim.train_seeded()
+        };
 
         JsValue::from_serde(&self.generation_stats(&stats)).unwrap()
     }
 
+    /// Starts recording a replay (see [`Player`]); recording continues
+    /// until [`Self::stop_recording()`].
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(sim::Recorder::new(&self.sim));
+    }
+
+    /// Stops recording and returns the replay; returns `None` if the
+    /// simulation hasn't been recorded.
+    pub fn stop_recording(&mut self) -> Option<Vec<u8>> {
+        let replay = self.recorder.take()?.finish();
+        let mut bytes = Vec::new();
+
+        replay
+            .save(&mut bytes)
+            .expect("writing into memory can't fail");
+
+        Some(bytes)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Restarts the world with the best bird ever seen; returns `false`
+    /// when there's none yet (i.e. before the first generation completes).
+    pub fn restart_with_champion(&mut self) -> bool {
+        self.sim.restart_with_champion_seeded()
+    }
+
+    /// Saves the entire simulation, so that it can be resumed later with
+    /// [`Self::load()`].
+    pub fn save(&self) -> Vec<u8> {
+        let mut checkpoint = Vec::new();
+
+        self.sim
+            .save(&mut checkpoint)
+            .expect("writing into memory can't fail");
+
+        checkpoint
+    }
+
+    pub fn load(checkpoint: &[u8]) -> Result<Simulation, JsError> {
+        let sim = sim::Simulation::load(checkpoint)?;
+
+        if sim.seed().is_none() {
+            return Err(JsError::new("checkpoint of an unseeded simulation"));
+        }
+
+        Ok(Self {
+            sim,
+            recorder: None,
+        })
+    }
+
+    /// Seed of this simulation's random number generator; passing it to the
+    /// constructor (together with the same config) reproduces the run.
+    pub fn seed(&self) -> u64 {
+        self.sim.seed().expect("simulation is seeded")
+    }
+
     /// Current generation number (how many times the population has
     /// evolved so far).
     pub fn generation(&self) -> usize {
         self.sim.generation()
     }
 
+    fn step_recorded(&mut self) -> Option<sim::Statistics> {
+        let stats = self.sim.step_seeded();
+
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(&self.sim);
+        }
+
+        stats
+    }
+
     fn generation_stats(&self, stats: &sim::Statistics) -> GenerationStats {
         GenerationStats {
             generation: self.sim.generation(),
             min_fitness: stats.min_fitness(),
             max_fitness: stats.max_fitness(),
             avg_fitness: stats.avg_fitness(),
+            median_fitness: stats.median_fitness(),
+            std_dev_fitness: stats.std_dev_fitness(),
+            q1_fitness: stats.q1_fitness(),
+            q3_fitness: stats.q3_fitness(),
+            gene_diversity: stats.gene_diversity(),
+            species_count: stats.species_count(),
+            species_sizes: stats.species_sizes().to_vec(),
+            pareto_front: self
+                .sim
+                .pareto_front()
+                .map(|front| front.objectives().to_vec())
+                .unwrap_or_default(),
         }
     }
 }
 
 impl Default for Simulation {
     fn default() -> Self {
-        Self::new(None, None, None, None, None)
+        Self::new(None, None, None, None, None, None, None).expect("default config is valid")
     }
 }
 
@@ -102,14 +221,63 @@ mod tests {
 
     #[test]
     fn new_creates_a_simulation_starting_at_generation_zero() {
-        let simulation = Simulation::new(None, None, None, None, None);
+        let simulation = Simulation::new(None, None, None, None, None, None, None).unwrap();
 
         assert_eq!(simulation.generation(), 0);
     }
 
+    #[test]
+    fn save_produces_a_loadable_checkpoint() {
+        let simulation = Simulation::new(Some(7), None, None, None, None, None, None).unwrap();
+
+        // (`Simulation::load()` itself can be called only from within wasm,
+        // because of the `JsError` it returns)
+        let loaded = sim::Simulation::load(simulation.save().as_slice()).unwrap();
+
+        assert_eq!(loaded.world().animals().len(), 7);
+    }
+
+    #[test]
+    fn same_seed_yields_the_same_run() {
+        let mut a = Simulation::new(None, None, None, None, None, None, Some(42)).unwrap();
+        let mut b = Simulation::new(None, None, None, None, None, None, Some(42)).unwrap();
+
+        assert_eq!(a.seed(), 42);
+
+        for _ in 0..100 {
+            let _ = a.step();
+            let _ = b.step();
+        }
+
+        for (a, b) in a.sim.world().animals().iter().zip(b.sim.world().animals()) {
+            assert_eq!(a.position, b.position);
+            assert_eq!(a.rotation, b.rotation);
+        }
+    }
+
+    #[test]
+    fn records_replays() {
+        let mut simulation =
+            Simulation::new(Some(7), Some(7), None, None, None, None, Some(42)).unwrap();
+
+        assert!(simulation.stop_recording().is_none());
+
+        simulation.start_recording();
+
+        for _ in 0..10 {
+            let _ = simulation.step();
+        }
+
+        let replay = sim::Replay::load(simulation.stop_recording().unwrap().as_slice()).unwrap();
+
+        assert!(!simulation.is_recording());
+        assert_eq!(replay.len(), 11);
+        assert_eq!(replay.seed(), Some(42));
+    }
+
     #[test]
     fn step_does_not_evolve_before_generation_length_is_reached() {
-        let mut simulation = Simulation::new(None, None, None, None, None);
+        let mut simulation = Simulation::new(None, None, None, None, None, None, None).unwrap();
 
         // GENERATION_LENGTH (in lib-simulation) is 2500; stepping fewer
         // times than that should never trigger evolution, and therefore
@@ -123,11 +291,11 @@ mod tests {
 
     #[test]
     fn generation_stats_reflects_the_current_generation_and_fitness_values() {
-        let simulation = Simulation::new(None, None, None, None, None);
+        let simulation = Simulation::new(None, None, None, None, None, None, None).unwrap();
         let stats = lib_simulation::Statistics::new(&[
-            AnimalIndividualStub(1.0),
-            AnimalIndividualStub(3.0),
-            AnimalIndividualStub(5.0),
+            AnimalIndividualStub::new(&[1.0, 0.0]),
+            AnimalIndividualStub::new(&[1.0, 2.0]),
+            AnimalIndividualStub::new(&[1.0, 4.0]),
         ]);
 
         let generation_stats = simulation.generation_stats(&stats);
@@ -136,23 +304,39 @@ mod tests {
         assert_eq!(generation_stats.min_fitness, 1.0);
         assert_eq!(generation_stats.max_fitness, 5.0);
         assert_eq!(generation_stats.avg_fitness, 3.0);
+        assert_eq!(generation_stats.median_fitness, 3.0);
+        assert_eq!(generation_stats.q1_fitness, 2.0);
+        assert_eq!(generation_stats.q3_fitness, 4.0);
+        assert!(generation_stats.std_dev_fitness > 0.0);
+        assert!(generation_stats.gene_diversity > 0.0);
+        assert!(generation_stats.pareto_front.is_empty());
+        assert_eq!(generation_stats.species_count, 1);
+        assert_eq!(generation_stats.species_sizes, vec![3]);
     }
 
     /// Minimal stand-in for `AnimalIndividual` used only to exercise
     /// `Statistics::new` without depending on a full `Animal`.
-    struct AnimalIndividualStub(f32);
+    struct AnimalIndividualStub(lib_genetic_algorithm::Chromosome);
+
+    impl AnimalIndividualStub {
+        fn new(genes: &[f32]) -> Self {
+            Self(genes.iter().copied().collect())
+        }
+    }
 
     impl lib_genetic_algorithm::Individual for AnimalIndividualStub {
-        fn create(_chromosome: lib_genetic_algorithm::Chromosome) -> Self {
-            unimplemented!()
+        fn create(chromosome: lib_genetic_algorithm::Chromosome) -> Self {
+            Self(chromosome)
         }
 
         fn chromosome(&self) -> &lib_genetic_algorithm::Chromosome {
-            unimplemented!()
+            &self.0
         }
 
+        /// Fitness is the sum of genes, like `TestIndividual` in the genetic
+        /// algorithm's own tests.
         fn fitness(&self) -> f32 {
-            self.0
+            self.0.iter().sum()
         }
     }
 }
@@ -0,0 +1,64 @@
+use crate::*;
+
+/// Plays back replays recorded with [`Simulation::start_recording()`].
+#[wasm_bindgen]
+pub struct Player {
+    player: sim::Player,
+}
+
+#[allow(deprecated)]
+#[wasm_bindgen]
+impl Player {
+    #[wasm_bindgen(constructor)]
+    pub fn new(replay: &[u8]) -> Result<Player, JsError> {
+        let replay = sim::Replay::load(replay)?;
+
+        if replay.is_empty() {
+            return Err(JsError::new("empty replay"));
+        }
+
+        Ok(Self {
+            player: sim::Player::new(replay),
+        })
+    }
+
+    /// Number of frames in the replay.
+    pub fn len(&self) -> usize {
+        self.player.replay().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.player.replay().is_empty()
+    }
+
+    /// Index of the current frame.
+    pub fn position(&self) -> usize {
+        self.player.position()
+    }
+
+    /// Generation the current frame belongs to.
+    pub fn generation(&self) -> usize {
+        self.player.frame().generation()
+    }
+
+    /// Returns the current frame, shaped like [`Simulation::world()`].
+    pub fn world(&self) -> JsValue {
+        JsValue::from_serde(&World::from(self.player.frame())).unwrap()
+    }
+
+    /// Jumps to given frame.
+    pub fn seek(&mut self, position: usize) {
+        self.player.seek(position);
+    }
+
+    /// Jumps to the first frame of given generation; returns `false` if
+    /// that generation hasn't been recorded.
+    pub fn seek_generation(&mut self, generation: usize) -> bool {
+        self.player.seek_generation(generation).is_some()
+    }
+
+    /// Moves to the next frame; returns `false` at the end of the replay.
+    pub fn advance(&mut self) -> bool {
+        self.player.advance().is_some()
+    }
+}
@@ -16,6 +16,16 @@ impl From<&sim::World> for World {
     }
 }
 
+impl From<&sim::Frame> for World {
+    fn from(frame: &sim::Frame) -> Self {
+        let animals = frame.animals().iter().map(Animal::from).collect();
+
+        let foods = frame.foods().iter().map(Food::from).collect();
+
+        Self { animals, foods }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,4 +42,18 @@ mod tests {
         assert_eq!(world.animals.len(), sim_world.animals().len());
         assert_eq!(world.foods.len(), sim_world.foods().len());
     }
+
+    #[test]
+    fn from_sim_frame_converts_all_animals_and_foods() {
+        let simulation = sim::Simulation::seeded(sim::Config {
+            seed: Some(1),
+            ..sim::Config::default()
+        });
+
+        let replay = sim::Recorder::new(&simulation).finish();
+        let world = World::from(&replay.frames()[0]);
+
+        assert_eq!(world.animals.len(), simulation.world().animals().len());
+        assert_eq!(world.foods.len(), simulation.world().foods().len());
+    }
 }
@@ -1,6 +1,6 @@
 use crate::*;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Animal {
     pub position: na::Point2<f32>,
     pub rotation: na::Rotation2<f32>,
@@ -8,28 +8,71 @@ pub struct Animal {
     pub eye: Eye,
     pub brain: Brain,
 
+    /// What this animal's brain remembers from the previous steps; empty
+    /// unless the brain is recurrent (see [`Config::brain_memory`]).
+    pub memory: nn::HiddenState,
+
     /// Number of foods eaten by this animal
     pub satiation: usize,
+
+    /// Number of steps this animal has lived for
+    pub age: usize,
+
+    /// Distance travelled by this animal
+    pub distance: f32,
+
+    /// Energy spent by this animal on accelerating and turning
+    pub energy: f32,
+
+    /// Positions this animal has been at, sampled every few steps; used to
+    /// describe its behaviour (see [`Behaviour`]).
+    pub trajectory: Vec<na::Point2<f32>>,
+
+    /// Per-gene mutation step sizes, inherited together with the brain;
+    /// empty unless [`Mutation::SelfAdaptive`] is in use.
+    pub mutation_step_sizes: Vec<f32>,
+
+    /// Island (sub-population) this animal evolves within; see
+    /// [`Config::islands`].
+    pub island: usize,
 }
 
 impl Animal {
     pub fn random(rng: &mut dyn Rng) -> Self {
+        Self::random_with(rng, BrainTopology::default())
+    }
+
+    pub fn random_with(rng: &mut dyn Rng, topology: BrainTopology) -> Self {
         let eye = Eye::default();
 
-        let brain = Brain::random(rng, &eye);
+        let brain = Brain::random(rng, &eye, topology);
 
         Self::new(eye, brain, rng)
     }
 
     pub fn from_chromosome(chromosome: ga::Chromosome, rng: &mut dyn Rng) -> Self {
+        Self::from_chromosome_with(chromosome, BrainTopology::default(), rng)
+    }
+
+    pub fn from_chromosome_with(
+        chromosome: ga::Chromosome,
+        topology: BrainTopology,
+        rng: &mut dyn Rng,
+    ) -> Self {
         let eye = Eye::default();
-        let brain = Brain::from_chromosome(chromosome, &eye);
+        let mutation_step_sizes = chromosome.step_sizes().to_vec();
+        let brain = Brain::from_chromosome(chromosome, &eye, topology);
 
-        Self::new(eye, brain, rng)
+        Self {
+            mutation_step_sizes,
+            ..Self::new(eye, brain, rng)
+        }
     }
 
     pub fn as_chromosome(&self) -> ga::Chromosome {
-        self.brain.as_chromosome()
+        let mut chromosome = self.brain.as_chromosome();
+        chromosome.set_step_sizes(self.mutation_step_sizes.clone());
+        chromosome
     }
 
     pub fn position(&self) -> na::Point2<f32> {
@@ -45,9 +88,16 @@ impl Animal {
             position: rng.random(),
             rotation: rng.random(),
             speed: 0.002,
+            memory: brain.nn.hidden_state(),
             eye,
             brain,
             satiation: 0,
+            age: 0,
+            distance: 0.0,
+            energy: 0.0,
+            trajectory: Vec::new(),
+            mutation_step_sizes: Vec::new(),
+            island: 0,
         }
     }
 }
@@ -92,4 +142,20 @@ mod tests {
             chromosome.into_iter().collect::<Vec<_>>(),
         );
     }
+
+    #[test]
+    fn chromosome_roundtrip_preserves_mutation_step_sizes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut animal = Animal::random(&mut rng);
+
+        let step_sizes: Vec<_> = (0..animal.as_chromosome().len())
+            .map(|idx| idx as f32 / 100.0)
+            .collect();
+
+        animal.mutation_step_sizes = step_sizes.clone();
+
+        let reconstructed = Animal::from_chromosome(animal.as_chromosome(), &mut rng);
+
+        assert_eq!(reconstructed.mutation_step_sizes, step_sizes);
+    }
 }
@@ -4,19 +4,38 @@ use crate::*;
 
 pub struct AnimalIndividual {
     fitness: f32,
+    objectives: Vec<f32>,
     chromosome: Chromosome,
+    age: usize,
 }
 
 impl AnimalIndividual {
     pub fn from_animal(animal: &Animal) -> Self {
+        Self::from_animal_with(animal, Objectives::Food)
+    }
+
+    pub fn from_animal_with(animal: &Animal, objectives: Objectives) -> Self {
         Self {
             fitness: animal.satiation as f32,
+            objectives: objectives.of(animal),
             chromosome: animal.as_chromosome(),
+            age: animal.age,
         }
     }
 
     pub fn into_animal(self, rng: &mut dyn Rng) -> Animal {
-        Animal::from_chromosome(self.chromosome, rng)
+        self.into_animal_with(BrainTopology::default(), rng)
+    }
+
+    pub fn into_animal_with(self, topology: BrainTopology, rng: &mut dyn Rng) -> Animal {
+        Animal::from_chromosome_with(self.chromosome, topology, rng)
+    }
+}
+
+impl AnimalIndividual {
+    /// Overrides fitness, e.g. with a novelty score (see [`FitnessMode`]).
+    pub fn with_fitness(self, fitness: f32) -> Self {
+        Self { fitness, ..self }
     }
 }
 
@@ -24,7 +43,9 @@ impl ga::Individual for AnimalIndividual {
     fn create(chromosome: ga::Chromosome) -> Self {
         Self {
             fitness: 0.0,
+            objectives: Vec::new(),
             chromosome,
+            age: 0,
         }
     }
 
@@ -35,12 +56,22 @@ impl ga::Individual for AnimalIndividual {
     fn fitness(&self) -> f32 {
         self.fitness
     }
+
+    fn age(&self) -> usize {
+        self.age
+    }
+}
+
+impl ga::MultiObjectiveIndividual for AnimalIndividual {
+    fn objectives(&self) -> Vec<f32> {
+        self.objectives.clone()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ga::Individual;
+    use ga::{Individual, MultiObjectiveIndividual};
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
 
@@ -55,6 +86,22 @@ mod tests {
         assert_eq!(individual.fitness(), 7.0);
     }
 
+    #[test]
+    fn from_animal_with_uses_requested_objectives() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut animal = Animal::random(&mut rng);
+        animal.satiation = 7;
+        animal.distance = 2.5;
+        animal.energy = 1.5;
+
+        let objectives =
+            |objectives| AnimalIndividual::from_animal_with(&animal, objectives).objectives();
+
+        assert_eq!(objectives(Objectives::Food), vec![7.0]);
+        assert_eq!(objectives(Objectives::FoodAndEnergy), vec![7.0, -1.5]);
+        assert_eq!(objectives(Objectives::FoodAndDistance), vec![7.0, -2.5]);
+    }
+
     #[test]
     fn from_animal_carries_over_the_chromosome() {
         let mut rng = ChaCha8Rng::from_seed(Default::default());
@@ -1,20 +1,46 @@
 use crate::*;
+use std::ops::Range;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Brain {
     pub nn: nn::Network,
+    pub topology: BrainTopology,
+}
+
+/// Shape of a brain, as configured by [`Config::brain_outputs`] and
+/// [`Config::brain_memory`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrainTopology {
+    pub outputs: BrainOutputs,
+
+    /// Whether the hidden layer is recurrent (see [`nn::HiddenState`])
+    pub recurrent: bool,
 }
 
 impl Brain {
-    pub fn random(rng: &mut dyn Rng, eye: &Eye) -> Self {
+    pub fn random(rng: &mut dyn Rng, eye: &Eye, topology: BrainTopology) -> Self {
         Self {
-            nn: nn::Network::random(rng, &Self::topology(eye)),
+            nn: nn::Network::random(rng, &Self::layers(eye, topology)),
+            topology,
         }
     }
 
-    pub fn from_chromosome(chromosome: ga::Chromosome, eye: &Eye) -> Self {
+    pub fn from_chromosome(chromosome: ga::Chromosome, eye: &Eye, topology: BrainTopology) -> Self {
         Self {
-            nn: nn::Network::from_weights(&Self::topology(eye), chromosome),
+            nn: nn::Network::from_weights(&Self::layers(eye, topology), chromosome),
+            topology,
+        }
+    }
+
+    /// Returns how much the bird wants to accelerate and to turn, given
+    /// what it sees (before these get limited by [`Simulation`]) and what
+    /// it remembers (which gets updated, if the brain is recurrent).
+    pub fn response(&self, vision: Vec<f32>, memory: &mut nn::HiddenState) -> (f32, f32) {
+        let response = self.nn.step(vision, memory);
+
+        match self.topology.outputs {
+            BrainOutputs::Unsigned | BrainOutputs::Bipolar => (response[0], response[1]),
+            BrainOutputs::Antagonistic => (response[0] - response[1], response[2] - response[3]),
         }
     }
 
@@ -22,15 +48,30 @@ impl Brain {
         self.nn.weights().collect()
     }
 
-    fn topology(eye: &Eye) -> [nn::LayerTopology; 3] {
+    /// Returns which genes of the brain's chromosome belong to which
+    /// neuron.
+    pub fn neuron_genes(eye: &Eye, topology: BrainTopology) -> Vec<Range<usize>> {
+        nn::Network::neuron_boundaries(&Self::layers(eye, topology))
+    }
+
+    /// Returns which genes of the brain's chromosome belong to which layer.
+    pub fn layer_genes(eye: &Eye, topology: BrainTopology) -> Vec<Range<usize>> {
+        nn::Network::layer_boundaries(&Self::layers(eye, topology))
+    }
+
+    fn layers(eye: &Eye, topology: BrainTopology) -> [nn::LayerTopology; 3] {
+        let output = match topology.outputs {
+            BrainOutputs::Unsigned => nn::LayerTopology::new(2),
+            BrainOutputs::Bipolar => {
+                nn::LayerTopology::new(2).with_activation(nn::Activation::Tanh)
+            }
+            BrainOutputs::Antagonistic => nn::LayerTopology::new(4),
+        };
+
         [
-            nn::LayerTopology {
-                neurons: eye.cells(),
-            },
-            nn::LayerTopology {
-                neurons: eye.cells(),
-            },
-            nn::LayerTopology { neurons: 2 },
+            nn::LayerTopology::new(eye.cells()),
+            nn::LayerTopology::new(eye.cells()).with_recurrent(topology.recurrent),
+            output,
         ]
     }
 }
@@ -38,14 +79,16 @@ impl Brain {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::RngExt;
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
+    use test_case::test_case;
 
     #[test]
     fn random_produces_a_network_matching_the_eyes_topology() {
         let mut rng = ChaCha8Rng::from_seed(Default::default());
         let eye = Eye::default();
-        let brain = Brain::random(&mut rng, &eye);
+        let brain = Brain::random(&mut rng, &eye, BrainTopology::default());
 
         let output = brain.nn.propagate(vec![0.0; eye.cells()]);
 
@@ -53,18 +96,149 @@ mod tests {
         assert_eq!(output.len(), 2);
     }
 
+    #[test]
+    fn gene_boundaries_cover_the_entire_chromosome() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let eye = Eye::default();
+        let brain = Brain::random(&mut rng, &eye, BrainTopology::default());
+        let genes = brain.as_chromosome().len();
+
+        let neurons = Brain::neuron_genes(&eye, BrainTopology::default());
+        let layers = Brain::layer_genes(&eye, BrainTopology::default());
+
+        // One neuron per eye cell in the hidden layer, plus 2 outputs
+        assert_eq!(neurons.len(), eye.cells() + 2);
+        assert_eq!(neurons.last().unwrap().end, genes);
+
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers.last().unwrap().end, genes);
+    }
+
     #[test]
     fn chromosome_roundtrip_preserves_the_weights() {
         let mut rng = ChaCha8Rng::from_seed(Default::default());
         let eye = Eye::default();
-        let brain = Brain::random(&mut rng, &eye);
+        let brain = Brain::random(&mut rng, &eye, BrainTopology::default());
 
         let chromosome = brain.as_chromosome();
-        let rebuilt = Brain::from_chromosome(chromosome.clone(), &eye);
+        let rebuilt = Brain::from_chromosome(chromosome.clone(), &eye, BrainTopology::default());
 
         assert_eq!(
             rebuilt.as_chromosome().into_iter().collect::<Vec<_>>(),
             chromosome.into_iter().collect::<Vec<_>>(),
         );
     }
+
+    /// Returns the responses (acceleration and rotation) of a bunch of
+    /// random brains to a bunch of random visions.
+    fn responses(outputs: BrainOutputs) -> Vec<(f32, f32)> {
+        let topology = BrainTopology {
+            outputs,
+            ..BrainTopology::default()
+        };
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let eye = Eye::default();
+
+        (0..50)
+            .flat_map(|_| {
+                let brain = Brain::random(&mut rng, &eye, topology);
+                let mut memory = brain.nn.hidden_state();
+
+                (0..10)
+                    .map(|_| {
+                        let vision = (0..eye.cells()).map(|_| rng.random()).collect();
+
+                        brain.response(vision, &mut memory)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unsigned_responses_are_never_negative() {
+        assert!(responses(BrainOutputs::Unsigned)
+            .iter()
+            .all(|&(speed, rotation)| speed >= 0.0 && rotation >= 0.0));
+    }
+
+    #[test_case(BrainOutputs::Bipolar ; "bipolar")]
+    #[test_case(BrainOutputs::Antagonistic ; "antagonistic")]
+    fn signed_responses_go_both_ways(outputs: BrainOutputs) {
+        let responses = responses(outputs);
+
+        assert!(responses.iter().any(|&(speed, _)| speed < 0.0));
+        assert!(responses.iter().any(|&(speed, _)| speed > 0.0));
+        assert!(responses.iter().any(|&(_, rotation)| rotation < 0.0));
+        assert!(responses.iter().any(|&(_, rotation)| rotation > 0.0));
+    }
+
+    #[test]
+    fn bipolar_responses_stay_within_unit_range() {
+        assert!(responses(BrainOutputs::Bipolar)
+            .iter()
+            .all(|&(speed, rotation)| speed.abs() < 1.0 && rotation.abs() < 1.0));
+    }
+
+    #[test]
+    fn antagonistic_outputs_come_in_pairs() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let eye = Eye::default();
+        let topology = BrainTopology {
+            outputs: BrainOutputs::Antagonistic,
+            ..BrainTopology::default()
+        };
+
+        let brain = Brain::random(&mut rng, &eye, topology);
+
+        assert_eq!(brain.nn.propagate(vec![0.0; eye.cells()]).len(), 4);
+
+        assert_eq!(Brain::neuron_genes(&eye, topology).len(), eye.cells() + 4);
+    }
+
+    #[test]
+    fn recurrent_brains_remember_what_they_have_seen() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let eye = Eye::default();
+
+        let topology = BrainTopology {
+            recurrent: true,
+            ..BrainTopology::default()
+        };
+
+        let brain = Brain::random(&mut rng, &eye, topology);
+        let mut memory = brain.nn.hidden_state();
+
+        let food = (0..eye.cells()).map(|cell| cell as f32 / 10.0).collect();
+        let nothing = vec![0.0; eye.cells()];
+
+        let forgetful = brain.response(nothing.clone(), &mut brain.nn.hidden_state());
+
+        brain.response(food, &mut memory);
+
+        // Seeing nothing right after seeing food is not the same as just
+        // seeing nothing
+        assert_ne!(brain.response(nothing.clone(), &mut memory), forgetful);
+
+        memory.reset();
+
+        assert_eq!(brain.response(nothing, &mut memory), forgetful);
+    }
+
+    #[test]
+    fn recurrent_brains_have_recurrent_genes() {
+        let eye = Eye::default();
+
+        let topology = BrainTopology {
+            recurrent: true,
+            ..BrainTopology::default()
+        };
+
+        // Each hidden neuron gets one extra gene per hidden neuron
+        assert_eq!(
+            Brain::neuron_genes(&eye, topology)[0].len(),
+            Brain::neuron_genes(&eye, BrainTopology::default())[0].len() + eye.cells()
+        );
+    }
 }
@@ -0,0 +1,380 @@
+use crate::*;
+use std::{error, fmt, io};
+
+/// Bytes every checkpoint starts with.
+const MAGIC: [u8; 4] = *b"SHCP";
+
+/// Version of the checkpoint format; bump whenever anything saved by
+/// [`Simulation::save()`] changes its shape.
+pub const CHECKPOINT_VERSION: u32 = 7;
+
+/// Everything (besides the world) needed to resume a simulation; the
+/// genetic algorithm's operators themselves are rebuilt from the config.
+#[derive(Serialize, Deserialize)]
+struct State {
+    age: usize,
+    generation: usize,
+    config: Config,
+    mutation_schedule: MutationSchedule,
+    mutation_factor: f32,
+    pareto_front: Option<ga::ParetoFront>,
+
+    /// Survivors of NSGA-II's previous generation, with their objectives
+    nsga2_parents: Vec<(ga::Chromosome, Vec<f32>)>,
+
+    novelty_archive: Option<ga::NoveltyArchive>,
+    cmaes: Option<ga::Cmaes>,
+    hall_of_fame: ga::HallOfFame,
+
+    /// State of each island's reproduction strategy
+    reproduction: Vec<Vec<(ga::Chromosome, f32)>>,
+
+    rng: Option<ChaCha8Rng>,
+}
+
+impl Simulation {
+    /// Saves the entire simulation - the world, the evolution's progress
+    /// and the config - so that it can be resumed later with
+    /// [`Self::load()`].
+    ///
+    /// Given the same random number generator (which is saved, too, if the
+    /// simulation owns it - see [`Self::seeded()`]), a loaded simulation
+    /// continues exactly as the saved one would.
+    pub fn save(&self, mut writer: impl io::Write) -> Result<(), CheckpointError> {
+        let state = State {
+            age: self.age,
+            generation: self.generation,
+            config: self.config,
+            mutation_schedule: self.mutation_schedule,
+            mutation_factor: self.mutation_factor,
+            pareto_front: self.pareto_front.clone(),
+            nsga2_parents: self.nsga2.parents().to_vec(),
+            novelty_archive: self.novelty_archive.clone(),
+            cmaes: self.cmaes.clone(),
+            hall_of_fame: self.hall_of_fame.clone(),
+            reproduction: self
+                .ga
+                .islands()
+                .iter()
+                .map(|island| island.reproduction_state())
+                .collect(),
+            rng: self.rng.clone(),
+        };
+
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&CHECKPOINT_VERSION.to_le_bytes())?;
+
+        bincode::serialize_into(&mut writer, &self.world)?;
+        bincode::serialize_into(&mut writer, &state)?;
+
+        Ok(())
+    }
+
+    /// Loads a simulation saved with [`Self::save()`].
+    pub fn load(mut reader: impl io::Read) -> Result<Self, CheckpointError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if magic != MAGIC {
+            return Err(CheckpointError::NotACheckpoint);
+        }
+
+        let mut version = [0; 4];
+        reader.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+
+        if version != CHECKPOINT_VERSION {
+            return Err(CheckpointError::UnsupportedVersion(version));
+        }
+
+        let world: World = bincode::deserialize_from(&mut reader)?;
+        let state: State = bincode::deserialize_from(&mut reader)?;
+
+        state.config.validate()?;
+
+        let mut simulation = Self::new(world, state.config);
+
+        simulation.age = state.age;
+        simulation.generation = state.generation;
+        simulation.mutation_schedule = state.mutation_schedule;
+        simulation.mutation_factor = state.mutation_factor;
+        simulation.pareto_front = state.pareto_front;
+        simulation.nsga2.set_parents(state.nsga2_parents);
+        simulation.novelty_archive = state.novelty_archive;
+        simulation.cmaes = state.cmaes;
+        simulation.hall_of_fame = state.hall_of_fame;
+        simulation.rng = state.rng;
+
+        for (island, state) in simulation
+            .ga
+            .islands_mut()
+            .iter_mut()
+            .zip(state.reproduction)
+        {
+            island.set_reproduction_state(state);
+        }
+
+        simulation.apply_mutation_factor();
+
+        Ok(simulation)
+    }
+}
+
+#[derive(Debug)]
+pub enum CheckpointError {
+    Io(io::Error),
+
+    /// Checkpoint is malformed
+    Format(bincode::Error),
+
+    /// Data doesn't look like a checkpoint at all
+    NotACheckpoint,
+
+    /// Checkpoint has been saved in a different version of the format
+    UnsupportedVersion(u32),
+
+    /// Checkpoint contains a config that no simulation could run with
+    InvalidConfig(ConfigError),
+}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "couldn't access checkpoint: {err}"),
+            Self::Format(err) => write!(f, "malformed checkpoint: {err}"),
+            Self::NotACheckpoint => write!(f, "not a checkpoint"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported checkpoint version: {version} (expected {CHECKPOINT_VERSION})"
+            ),
+            Self::InvalidConfig(err) => write!(f, "invalid config in checkpoint: {err}"),
+        }
+    }
+}
+
+impl error::Error for CheckpointError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Format(err) => Some(err),
+            Self::InvalidConfig(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CheckpointError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<bincode::Error> for CheckpointError {
+    fn from(err: bincode::Error) -> Self {
+        Self::Format(err)
+    }
+}
+
+impl From<ConfigError> for CheckpointError {
+    fn from(err: ConfigError) -> Self {
+        Self::InvalidConfig(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use test_case::test_case;
+
+    /// Returns the entire state of the world, in a comparable form.
+    fn snapshot(simulation: &Simulation) -> Vec<u8> {
+        bincode::serialize(simulation.world()).unwrap()
+    }
+
+    fn resumes_exactly(config: Config, steps_before: usize, steps_after: usize) {
+        // (a small world keeps the test reasonably fast)
+        let config = Config {
+            num_animals: 12,
+            num_foods: 12,
+            ..config
+        };
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        for _ in 0..steps_before {
+            simulation.step(&mut rng);
+        }
+
+        let mut checkpoint = Vec::new();
+        simulation.save(&mut checkpoint).unwrap();
+
+        let mut resumed = Simulation::load(checkpoint.as_slice()).unwrap();
+        let mut resumed_rng = rng.clone();
+
+        assert_eq!(resumed.generation(), simulation.generation());
+        assert_eq!(resumed.config, simulation.config);
+        assert_eq!(snapshot(&resumed), snapshot(&simulation));
+
+        for _ in 0..steps_after {
+            let stats = simulation.step(&mut rng);
+            let resumed_stats = resumed.step(&mut resumed_rng);
+
+            assert_eq!(
+                stats.map(|stats| stats.avg_fitness()),
+                resumed_stats.map(|stats| stats.avg_fitness()),
+            );
+        }
+
+        assert_eq!(resumed.generation(), simulation.generation());
+        assert_eq!(snapshot(&resumed), snapshot(&simulation));
+    }
+
+    #[test_case(Config::default() ; "default")]
+    #[test_case(Config {
+        mutation_schedule: MutationSchedule::LinearDecay(ga::LinearDecay::new(1.0, 0.1, 10)),
+        islands: 2,
+        migration: ga::Migration::new(ga::MigrationTopology::Ring, 1, 1),
+        ..Config::default()
+    } ; "islands with a mutation schedule")]
+    #[test_case(Config {
+        reproduction: Reproduction::DifferentialEvolution {
+            variant: ga::DifferentialEvolutionVariant::Rand1Bin,
+            f: 0.5,
+            cr: 0.9,
+        },
+        ..Config::default()
+    } ; "differential evolution")]
+    #[test_case(Config {
+        fitness_mode: FitnessMode::Novelty { k: 5, threshold: 0.1 },
+        behaviour: Behaviour::Trajectory { samples: 4 },
+        ..Config::default()
+    } ; "novelty search")]
+    #[test_case(Config {
+        optimizer: Optimizer::Cmaes { sigma: 0.3 },
+        ..Config::default()
+    } ; "cmaes")]
+    #[test_case(Config {
+        brain_outputs: BrainOutputs::Antagonistic,
+        crossover: Crossover::Neuron,
+        ..Config::default()
+    } ; "antagonistic outputs")]
+    #[test_case(Config {
+        brain_memory: true,
+        ..Config::default()
+    } ; "memory")]
+    #[test_case(Config {
+        objectives: Objectives::FoodAndEnergy,
+        ..Config::default()
+    } ; "nsga2")]
+    fn resumes_exactly_where_it_left_off(config: Config) {
+        resumes_exactly(config, 2600, 2500);
+    }
+
+    #[test]
+    fn resumes_steady_state_evolution_exactly_where_it_left_off() {
+        let config = Config {
+            evolution: Evolution::SteadyState {
+                interval: 50,
+                count: 2,
+                replacement: ga::Replacement::TournamentLoser { size: 3 },
+            },
+            mutation_schedule: MutationSchedule::LinearDecay(ga::LinearDecay::new(1.0, 0.1, 10)),
+            ..Config::default()
+        };
+
+        resumes_exactly(config, 2600, 1500);
+    }
+
+    #[test]
+    fn resumes_seeded_simulations_with_their_own_rng() {
+        let config = Config {
+            num_animals: 12,
+            num_foods: 12,
+            seed: Some(1234),
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::seeded(config);
+
+        for _ in 0..100 {
+            simulation.step_seeded();
+        }
+
+        let mut checkpoint = Vec::new();
+        simulation.save(&mut checkpoint).unwrap();
+
+        let mut resumed = Simulation::load(checkpoint.as_slice()).unwrap();
+
+        assert_eq!(resumed.seed(), Some(1234));
+
+        for _ in 0..2500 {
+            simulation.step_seeded();
+            resumed.step_seeded();
+        }
+
+        assert_eq!(snapshot(&resumed), snapshot(&simulation));
+    }
+
+    #[test]
+    fn rejects_other_data() {
+        let err = Simulation::load(b"hello, world".as_slice()).err().unwrap();
+
+        assert!(matches!(err, CheckpointError::NotACheckpoint));
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut checkpoint = MAGIC.to_vec();
+        checkpoint.extend((CHECKPOINT_VERSION + 1).to_le_bytes());
+
+        let err = Simulation::load(checkpoint.as_slice()).err().unwrap();
+
+        assert!(
+            matches!(err, CheckpointError::UnsupportedVersion(v) if v == CHECKPOINT_VERSION + 1)
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_checkpoints() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let simulation = Simulation::random(&mut rng);
+
+        let mut checkpoint = Vec::new();
+        simulation.save(&mut checkpoint).unwrap();
+        checkpoint.truncate(checkpoint.len() / 2);
+
+        let err = Simulation::load(checkpoint.as_slice()).err().unwrap();
+
+        assert!(matches!(err, CheckpointError::Format(_)));
+    }
+
+    #[test]
+    fn rejects_invalid_configs() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        // (e.g. a checkpoint edited by hand)
+        simulation.config.elitism = ga::Elitism::Count(2);
+        simulation.config.reproduction = Reproduction::DifferentialEvolution {
+            variant: ga::DifferentialEvolutionVariant::Rand1Bin,
+            f: 0.5,
+            cr: 0.9,
+        };
+
+        let mut checkpoint = Vec::new();
+        simulation.save(&mut checkpoint).unwrap();
+
+        let err = Simulation::load(checkpoint.as_slice()).err().unwrap();
+
+        assert!(matches!(
+            err,
+            CheckpointError::InvalidConfig(ConfigError::Incompatible(
+                "differential evolution",
+                "elitism"
+            ))
+        ));
+    }
+}
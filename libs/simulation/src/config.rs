@@ -0,0 +1,1054 @@
+use crate::*;
+use std::{error, fmt};
+
+/// Tunable knobs affecting how a [`Simulation`] is set up.
+///
+/// All fields have sane defaults (see [`Config::default`]) matching the
+/// values this simulation originally shipped with.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Config {
+    /// How many animals (birds) populate the world.
+    pub num_animals: usize,
+
+    /// How many foods populate the world.
+    pub num_foods: usize,
+
+    /// Probability of a single gene mutating during evolution.
+    ///
+    /// - 0.0 = no genes will be touched
+    /// - 1.0 = all genes will be touched
+    pub mutation_chance: f32,
+
+    /// Magnitude of a mutation, when it happens (see [`Mutation`] for what
+    /// it means for each of the methods).
+    ///
+    /// - 0.0 = touched genes will not be modified
+    pub mutation_coeff: f32,
+
+    /// How genes get mutated.
+    pub mutation: Mutation,
+
+    /// How mutation's chance and magnitude change as generations go by.
+    pub mutation_schedule: MutationSchedule,
+
+    /// Maximum speed a bird can reach.
+    pub max_speed: f32,
+
+    /// How birds' brains express acceleration and rotation.
+    pub brain_outputs: BrainOutputs,
+
+    /// Whether birds' brains feed their hidden layer back into itself,
+    /// letting birds remember what they've seen during the previous steps
+    /// (e.g. food that has just left their field of view).
+    ///
+    /// Birds' memories get wiped whenever they are born anew, i.e. on each
+    /// evolution.
+    pub brain_memory: bool,
+
+    /// How many of the best birds get carried over, unchanged, into the
+    /// next generation (on each island separately).
+    pub elitism: ga::Elitism,
+
+    /// Into how many sub-populations ("islands") birds get split; each
+    /// island evolves on its own, see [`ga::IslandModel`].
+    ///
+    /// - 1 = all birds evolve together
+    pub islands: usize,
+
+    /// How birds migrate between islands; has no effect with a single
+    /// island.
+    pub migration: ga::Migration,
+
+    /// Whether birds with similar brains form species that compete mostly
+    /// among themselves (on each island separately).
+    pub speciation: Option<ga::Speciation>,
+
+    /// How two parents get combined into a child.
+    pub crossover: Crossover,
+
+    /// How offspring get produced by the genetic algorithm.
+    pub reproduction: Reproduction,
+
+    /// How satiation gets transformed before birds are picked for
+    /// reproduction.
+    pub fitness_scaling: Scaling,
+
+    /// What birds are evolved for.
+    pub objectives: Objectives,
+
+    /// Whether birds are rewarded for satiation, for behaving differently
+    /// than the others, or for both; applies only to single-objective
+    /// evolution.
+    pub fitness_mode: FitnessMode,
+
+    /// How birds' behaviour is described for novelty search.
+    pub behaviour: Behaviour,
+
+    /// What evolves the birds.
+    pub optimizer: Optimizer,
+
+    /// Whether birds get replaced all at once, at the end of each
+    /// generation, or a few at a time, as the simulation goes.
+    pub evolution: Evolution,
+
+    /// How many of the best birds ever seen get remembered (see
+    /// [`Simulation::hall_of_fame()`]).
+    pub hall_of_fame: usize,
+
+    /// Seed of the simulation's own random number generator (see
+    /// [`Simulation::seeded()`]); the same seed yields the same run, step
+    /// for step.
+    ///
+    /// - None = a random seed gets picked
+    pub seed: Option<u64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            num_animals: 40,
+            num_foods: 60,
+            mutation_chance: 0.01,
+            mutation_coeff: 0.3,
+            mutation: Mutation::Uniform,
+            mutation_schedule: MutationSchedule::Constant,
+            max_speed: SPEED_MAX,
+            brain_outputs: BrainOutputs::Unsigned,
+            brain_memory: false,
+            elitism: ga::Elitism::Count(0),
+            islands: 1,
+            migration: ga::Migration::default(),
+            speciation: None,
+            crossover: Crossover::Uniform,
+            reproduction: Reproduction::Standard,
+            fitness_scaling: Scaling::None,
+            objectives: Objectives::Food,
+            fitness_mode: FitnessMode::Satiation,
+            behaviour: Behaviour::FinalPosition,
+            optimizer: Optimizer::GeneticAlgorithm,
+            evolution: Evolution::Generational,
+            hall_of_fame: 10,
+            seed: None,
+        }
+    }
+}
+
+impl Config {
+    /// Checks that this config describes a simulation that can actually
+    /// run - e.g. that it doesn't combine features that don't work
+    /// together.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        use ConfigError::Incompatible;
+
+        if self.islands == 0 || self.islands > self.num_animals {
+            return Err(ConfigError::InvalidIslands);
+        }
+
+        let has_islands = self.islands > 1;
+        let has_elitism = !matches!(self.elitism, ga::Elitism::Count(0));
+        let is_multi_objective = self.objectives.is_multi_objective();
+
+        if has_islands && is_multi_objective {
+            return Err(Incompatible("islands", "multiple objectives"));
+        }
+
+        if let Optimizer::Cmaes { .. } = self.optimizer {
+            let cmaes = "CMA-ES";
+
+            if has_islands {
+                return Err(Incompatible(cmaes, "islands"));
+            }
+
+            if is_multi_objective {
+                return Err(Incompatible(cmaes, "multiple objectives"));
+            }
+
+            if has_elitism {
+                return Err(Incompatible(cmaes, "elitism"));
+            }
+
+            if self.speciation.is_some() {
+                return Err(Incompatible(cmaes, "speciation"));
+            }
+
+            if self.fitness_scaling != Scaling::None {
+                return Err(Incompatible(cmaes, "fitness scaling"));
+            }
+
+            if self.reproduction != Reproduction::Standard {
+                return Err(Incompatible(cmaes, "differential evolution"));
+            }
+
+            if self.crossover != Crossover::Uniform {
+                return Err(Incompatible(cmaes, "other crossovers"));
+            }
+
+            if self.mutation != Mutation::Uniform {
+                return Err(Incompatible(cmaes, "other mutations"));
+            }
+
+            if self.mutation_schedule != MutationSchedule::Constant {
+                return Err(Incompatible(cmaes, "mutation schedules"));
+            }
+        }
+
+        if let Reproduction::DifferentialEvolution { .. } = self.reproduction {
+            // Migrants would take place of trials, which are paired with
+            // their targets by position
+            if has_islands {
+                return Err(Incompatible("differential evolution", "islands"));
+            }
+
+            if has_elitism {
+                return Err(Incompatible("differential evolution", "elitism"));
+            }
+
+            if self.num_animals / self.islands < 4 {
+                return Err(ConfigError::TooFewAnimals);
+            }
+        }
+
+        if let Evolution::SteadyState {
+            interval, count, ..
+        } = self.evolution
+        {
+            let steady_state = "steady-state evolution";
+
+            if interval == 0 || count == 0 {
+                return Err(ConfigError::InvalidSteadyState);
+            }
+
+            if self.optimizer != Optimizer::GeneticAlgorithm {
+                return Err(Incompatible(steady_state, "CMA-ES"));
+            }
+
+            if is_multi_objective {
+                return Err(Incompatible(steady_state, "multiple objectives"));
+            }
+
+            if self.reproduction != Reproduction::Standard {
+                return Err(Incompatible(steady_state, "differential evolution"));
+            }
+
+            if self.fitness_mode != FitnessMode::Satiation {
+                return Err(Incompatible(steady_state, "novelty search"));
+            }
+
+            if self.speciation.is_some() {
+                return Err(Incompatible(steady_state, "speciation"));
+            }
+
+            if has_elitism {
+                return Err(Incompatible(steady_state, "elitism"));
+            }
+
+            if has_islands && self.migration.migrants() > 0 {
+                return Err(Incompatible(steady_state, "migration"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reason why [`Config::validate()`] rejected a config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    /// There are no islands, or more islands than birds
+    InvalidIslands,
+
+    /// Differential evolution needs at least four birds
+    TooFewAnimals,
+
+    /// Steady-state evolution replaces zero birds, or does so every zero
+    /// steps
+    InvalidSteadyState,
+
+    /// Two features that don't work together
+    Incompatible(&'static str, &'static str),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidIslands => write!(f, "there must be between one island and one per bird"),
+            Self::TooFewAnimals => write!(f, "differential evolution needs at least four birds"),
+            Self::InvalidSteadyState => write!(
+                f,
+                "steady-state evolution needs a non-zero interval and count"
+            ),
+            Self::Incompatible(a, b) => write!(f, "{a} doesn't support {b}"),
+        }
+    }
+}
+
+impl error::Error for ConfigError {}
+
+/// How birds' brains express acceleration and rotation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BrainOutputs {
+    /// Two ReLU neurons - one for acceleration, one for rotation; since
+    /// they never go below zero, birds can neither slow down nor turn
+    /// clockwise.
+    #[default]
+    Unsigned,
+
+    /// Two tanh neurons, able to go both ways.
+    Bipolar,
+
+    /// Two pairs of ReLU neurons pulling in opposite directions (like
+    /// muscles do) - acceleration is the first neuron minus the second one,
+    /// and rotation is the third neuron minus the fourth one.
+    Antagonistic,
+}
+
+/// How the genetic algorithm produces offspring.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Reproduction {
+    /// Parents get selected, crossed over and then mutated, as configured
+    /// by [`Config::crossover`], [`Config::mutation`] and so on.
+    Standard,
+
+    /// See [`ga::DifferentialEvolution`]; doesn't support elitism nor
+    /// islands, needs at least four birds, and doesn't use crossover,
+    /// mutation, fitness scaling nor speciation.
+    DifferentialEvolution {
+        variant: ga::DifferentialEvolutionVariant,
+        f: f32,
+        cr: f32,
+    },
+}
+
+/// What evolves the birds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Optimizer {
+    /// See [`ga::GeneticAlgorithm`] - configured by the rest of [`Config`].
+    GeneticAlgorithm,
+
+    /// See [`ga::Cmaes`]; `sigma` is the initial step size.
+    ///
+    /// CMA-ES neither crosses over nor mutates genes, so most of the
+    /// genetic algorithm's knobs don't apply to it: elitism, speciation,
+    /// fitness scaling, reproduction, crossover, mutation and mutation
+    /// schedule must be left at their defaults (`mutation_chance` and
+    /// `mutation_coeff` are ignored). It doesn't support islands nor
+    /// multiple objectives either.
+    Cmaes { sigma: f32 },
+}
+
+/// How birds get replaced by their offspring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Evolution {
+    /// All birds get replaced at once, at the end of each generation.
+    Generational,
+
+    /// Every `interval` steps, `count` birds (picked by `replacement`
+    /// among the ones that have lived for a while) get replaced by
+    /// offspring of their island; the world never resets.
+    ///
+    /// Birds get judged by how much they eat per generation's worth of
+    /// steps, so that ones born recently compete fairly with old ones.
+    /// Supports only the standard reproduction of the genetic algorithm,
+    /// for a single objective and without novelty, speciation nor elitism;
+    /// islands are supported, but only without migration (i.e. with zero
+    /// migrants).
+    SteadyState {
+        interval: usize,
+        count: usize,
+        replacement: ga::Replacement,
+    },
+}
+
+impl Evolution {
+    pub fn is_steady_state(&self) -> bool {
+        *self != Self::Generational
+    }
+}
+
+/// What birds are evolved for.
+///
+/// With more than one objective birds get evolved by NSGA-II (see
+/// [`ga::Nsga2`]), which looks for the best trade-offs between them instead
+/// of a single "best" bird; this doesn't support islands nor fitness
+/// scaling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Objectives {
+    /// Eat as much as possible.
+    Food,
+
+    /// Eat as much as possible, while spending as little energy as
+    /// possible on accelerating and turning.
+    FoodAndEnergy,
+
+    /// Eat as much as possible, while travelling as little as possible.
+    FoodAndDistance,
+}
+
+impl Objectives {
+    pub fn is_multi_objective(&self) -> bool {
+        *self != Self::Food
+    }
+
+    /// Returns objectives (all of which are maximized) achieved by given
+    /// animal.
+    pub(crate) fn of(&self, animal: &Animal) -> Vec<f32> {
+        let food = animal.satiation as f32;
+
+        match self {
+            Self::Food => vec![food],
+            Self::FoodAndEnergy => vec![food, -animal.energy],
+            Self::FoodAndDistance => vec![food, -animal.distance],
+        }
+    }
+}
+
+/// What birds are rewarded for; see [`ga::NoveltyArchive`] for details on
+/// novelty.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FitnessMode {
+    /// Birds are rewarded for eating.
+    Satiation,
+
+    /// Birds are rewarded for behaving differently than birds of the
+    /// current population and the ones archived before; `k` is the number of
+    /// nearest neighbours novelty is averaged over, and `threshold` is the
+    /// novelty needed for a behaviour to get archived.
+    Novelty { k: usize, threshold: f32 },
+
+    /// Both of the above - satiation and novelty get normalized to
+    /// `0.0..=1.0` within the population and then mixed:
+    ///
+    /// - `novelty_weight` = 0.0 = just satiation
+    /// - `novelty_weight` = 1.0 = just novelty
+    Hybrid {
+        k: usize,
+        threshold: f32,
+        novelty_weight: f32,
+    },
+}
+
+impl FitnessMode {
+    pub(crate) fn novelty_archive(&self) -> Option<ga::NoveltyArchive> {
+        match *self {
+            Self::Satiation => None,
+            Self::Novelty { k, threshold } | Self::Hybrid { k, threshold, .. } => {
+                Some(ga::NoveltyArchive::new(k, threshold).with_max_size(NOVELTY_ARCHIVE_SIZE))
+            }
+        }
+    }
+
+    /// Returns fitness of each bird, given its satiation and novelty.
+    pub(crate) fn fitness(&self, satiation: &[f32], novelty: &[f32]) -> Vec<f32> {
+        match *self {
+            Self::Satiation => satiation.to_vec(),
+            Self::Novelty { .. } => novelty.to_vec(),
+            Self::Hybrid { novelty_weight, .. } => {
+                assert!((0.0..=1.0).contains(&novelty_weight));
+
+                let normalize = |values: &[f32]| {
+                    let max = values.iter().copied().fold(0.0, f32::max);
+
+                    values
+                        .iter()
+                        .map(|value| if max > 0.0 { value / max } else { 0.0 })
+                        .collect::<Vec<_>>()
+                };
+
+                normalize(satiation)
+                    .into_iter()
+                    .zip(normalize(novelty))
+                    .map(|(satiation, novelty)| {
+                        (1.0 - novelty_weight) * satiation + novelty_weight * novelty
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// How a bird's behaviour gets described (as a vector of numbers, i.e. the
+/// behaviour descriptor) for novelty search.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Behaviour {
+    /// Where the bird ended up.
+    FinalPosition,
+
+    /// How much time the bird has spent in each cell of a `cells` x `cells`
+    /// grid laid over the world.
+    Visitation { cells: usize },
+
+    /// Where the bird has been at `samples` evenly spaced moments of its
+    /// life.
+    Trajectory { samples: usize },
+}
+
+impl Behaviour {
+    pub(crate) fn describe(&self, animal: &Animal) -> Vec<f32> {
+        match *self {
+            Self::FinalPosition => vec![animal.position.x, animal.position.y],
+
+            Self::Visitation { cells } => {
+                assert!(cells > 0);
+
+                let mut histogram = vec![0.0; cells * cells];
+
+                if animal.trajectory.is_empty() {
+                    return histogram;
+                }
+
+                let cell = |coord: f32| ((coord * cells as f32) as usize).min(cells - 1);
+
+                for position in &animal.trajectory {
+                    histogram[cell(position.y) * cells + cell(position.x)] += 1.0;
+                }
+
+                let total = animal.trajectory.len() as f32;
+
+                histogram.iter().map(|count| count / total).collect()
+            }
+
+            Self::Trajectory { samples } => {
+                assert!(samples > 0);
+
+                let Some(last) = animal.trajectory.len().checked_sub(1) else {
+                    return [animal.position.x, animal.position.y].repeat(samples);
+                };
+
+                (0..samples)
+                    .flat_map(|sample| {
+                        let idx = if samples > 1 {
+                            sample * last / (samples - 1)
+                        } else {
+                            last
+                        };
+
+                        [animal.trajectory[idx].x, animal.trajectory[idx].y]
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Fitness scaling applied by the genetic algorithm, see
+/// [`ga::FitnessScaling`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Scaling {
+    /// Raw satiation is used as-is.
+    None,
+
+    /// See [`ga::LinearScaling`].
+    Linear { multiplier: f32 },
+
+    /// See [`ga::SigmaTruncation`].
+    SigmaTruncation { c: f32 },
+
+    /// See [`ga::Windowing`].
+    Windowing { offset: f32 },
+
+    /// See [`ga::ExponentialRanking`].
+    ExponentialRanking { base: f32 },
+}
+
+impl ga::FitnessScaling for Scaling {
+    fn scale(&self, fitness: &[f32]) -> Vec<f32> {
+        match *self {
+            Self::None => ga::NoScaling.scale(fitness),
+            Self::Linear { multiplier } => ga::LinearScaling::new(multiplier).scale(fitness),
+            Self::SigmaTruncation { c } => ga::SigmaTruncation::new(c).scale(fitness),
+            Self::Windowing { offset } => ga::Windowing::new(offset).scale(fitness),
+            Self::ExponentialRanking { base } => ga::ExponentialRanking::new(base).scale(fitness),
+        }
+    }
+}
+
+/// Crossover method used by the genetic algorithm, see
+/// [`ga::CrossoverMethod`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Crossover {
+    /// See [`ga::UniformCrossover`].
+    Uniform,
+
+    /// See [`ga::SinglePointCrossover`].
+    SinglePoint,
+
+    /// See [`ga::KPointCrossover`].
+    KPoint { k: usize },
+
+    /// See [`ga::ArithmeticCrossover`].
+    Arithmetic { alpha: f32 },
+
+    /// See [`ga::BlendCrossover`].
+    Blend { alpha: f32 },
+
+    /// See [`ga::SimulatedBinaryCrossover`].
+    SimulatedBinary { eta: f32 },
+
+    /// Children inherit whole neurons (bias together with all of its
+    /// weights) from either parent; see [`ga::BlockCrossover`].
+    Neuron,
+
+    /// Children inherit whole layers from either parent; see
+    /// [`ga::BlockCrossover`].
+    Layer,
+}
+
+/// Crossover method, ready to cross brains of a given shape over.
+#[derive(Debug, Clone)]
+pub(crate) struct CrossoverOperator {
+    pub crossover: Crossover,
+
+    /// Neurons or layers of the brain, computed up front for block
+    /// crossovers; `None` for the other methods
+    pub blocks: Option<ga::BlockCrossover>,
+}
+
+impl ga::CrossoverMethod for CrossoverOperator {
+    fn crossover(
+        &self,
+        rng: &mut dyn Rng,
+        parent_a: &ga::Chromosome,
+        parent_b: &ga::Chromosome,
+    ) -> ga::Chromosome {
+        match self.crossover {
+            Crossover::Uniform => ga::UniformCrossover.crossover(rng, parent_a, parent_b),
+            Crossover::SinglePoint => ga::SinglePointCrossover.crossover(rng, parent_a, parent_b),
+            Crossover::KPoint { k } => {
+                ga::KPointCrossover::new(k).crossover(rng, parent_a, parent_b)
+            }
+            Crossover::Arithmetic { alpha } => {
+                ga::ArithmeticCrossover::new(alpha).crossover(rng, parent_a, parent_b)
+            }
+            Crossover::Blend { alpha } => {
+                ga::BlendCrossover::new(alpha).crossover(rng, parent_a, parent_b)
+            }
+            Crossover::SimulatedBinary { eta } => {
+                ga::SimulatedBinaryCrossover::new(eta).crossover(rng, parent_a, parent_b)
+            }
+            Crossover::Neuron | Crossover::Layer => self
+                .blocks
+                .as_ref()
+                .expect("block crossover without blocks")
+                .crossover(rng, parent_a, parent_b),
+        }
+    }
+}
+
+impl Config {
+    pub(crate) fn crossover_operator(&self) -> CrossoverOperator {
+        let eye = Eye::default();
+
+        let blocks = match self.crossover {
+            Crossover::Neuron => Some(Brain::neuron_genes(&eye, self.brain_topology())),
+            Crossover::Layer => Some(Brain::layer_genes(&eye, self.brain_topology())),
+            _ => None,
+        };
+
+        CrossoverOperator {
+            crossover: self.crossover,
+            blocks: blocks.map(ga::BlockCrossover::new),
+        }
+    }
+
+    pub(crate) fn brain_topology(&self) -> BrainTopology {
+        BrainTopology {
+            outputs: self.brain_outputs,
+            recurrent: self.brain_memory,
+        }
+    }
+
+    pub(crate) fn mutation_operator(&self) -> MutationOperator {
+        MutationOperator {
+            mutation: self.mutation,
+            chance: self.mutation_chance,
+            coeff: self.mutation_coeff,
+        }
+    }
+}
+
+/// Mutation method used by the genetic algorithm, see
+/// [`ga::MutationMethod`] for details.
+///
+/// Each method touches genes with [`Config::mutation_chance`] probability.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Mutation {
+    /// Adds a random number from `-mutation_coeff..mutation_coeff`; see
+    /// [`ga::UniformMutation`].
+    Uniform,
+
+    /// Adds a normally distributed number, with `mutation_coeff` being the
+    /// standard deviation; see [`ga::NormalMutation`].
+    Gaussian,
+
+    /// Moves genes by a fraction of `-1.0..=1.0` (the range genes of new
+    /// brains are drawn from), keeping them within that range;
+    /// `mutation_coeff` is not used. See [`ga::PolynomialMutation`].
+    Polynomial { eta: f32 },
+
+    /// Draws genes anew from `-1.0..=1.0` (the range genes of new brains are
+    /// drawn from); `mutation_coeff` is not used. See [`ga::ResetMutation`].
+    Reset,
+
+    /// Adds a normally distributed number, with each gene carrying its own
+    /// standard deviation that evolves together with the bird;
+    /// `mutation_coeff` is the standard deviation new birds start with
+    /// (floored at [`ga::SelfAdaptiveMutation::MIN_STEP_SIZE`], so that
+    /// mutation schedules can decay it all the way down to zero). See
+    /// [`ga::SelfAdaptiveMutation`].
+    SelfAdaptive,
+}
+
+/// Mutation schedule used by the simulation, see [`ga::MutationSchedule`]
+/// for details.
+///
+/// Unlike the other knobs, schedules may keep track of how the evolution
+/// goes - so the simulation works on its own copy, leaving the one in
+/// [`Config`] intact.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MutationSchedule {
+    /// Mutation is always applied as configured.
+    Constant,
+
+    /// See [`ga::LinearDecay`].
+    LinearDecay(ga::LinearDecay),
+
+    /// See [`ga::ExponentialDecay`].
+    ExponentialDecay(ga::ExponentialDecay),
+
+    /// See [`ga::OneFifthRule`].
+    OneFifthRule(ga::OneFifthRule),
+}
+
+impl ga::MutationSchedule for MutationSchedule {
+    fn factor(&mut self, generation: usize, statistics: &ga::Statistics) -> f32 {
+        match self {
+            Self::Constant => 1.0,
+            Self::LinearDecay(schedule) => schedule.factor(generation, statistics),
+            Self::ExponentialDecay(schedule) => schedule.factor(generation, statistics),
+            Self::OneFifthRule(schedule) => schedule.factor(generation, statistics),
+        }
+    }
+}
+
+/// [`Mutation`] together with the chance and coefficient it is applied
+/// with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct MutationOperator {
+    pub mutation: Mutation,
+    pub chance: f32,
+    pub coeff: f32,
+}
+
+impl MutationOperator {
+    /// Returns this operator with chance and coefficient multiplied by
+    /// `factor` (see [`ga::MutationSchedule`]).
+    pub fn scaled(self, factor: f32) -> Self {
+        Self {
+            chance: (self.chance * factor).clamp(0.0, 1.0),
+            coeff: self.coeff * factor,
+            ..self
+        }
+    }
+}
+
+impl ga::MutationMethod for MutationOperator {
+    fn mutate(&self, rng: &mut dyn Rng, child: &mut ga::Chromosome) {
+        let Self {
+            mutation,
+            chance,
+            coeff,
+        } = *self;
+
+        match mutation {
+            Mutation::Uniform => ga::UniformMutation::new(chance, coeff).mutate(rng, child),
+            Mutation::Gaussian => ga::NormalMutation::new(chance, coeff).mutate(rng, child),
+            Mutation::Polynomial { eta } => {
+                ga::PolynomialMutation::new(chance, eta, -1.0..=1.0).mutate(rng, child)
+            }
+            Mutation::Reset => ga::ResetMutation::new(chance, -1.0..=1.0).mutate(rng, child),
+            Mutation::SelfAdaptive => ga::SelfAdaptiveMutation::new(
+                chance,
+                coeff.max(ga::SelfAdaptiveMutation::MIN_STEP_SIZE),
+            )
+            .mutate(rng, child),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ga::{CrossoverMethod as _, FitnessScaling as _, MutationMethod as _};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use test_case::test_case;
+
+    #[test]
+    fn validation_explains_what_is_wrong() {
+        assert_eq!(Config::default().validate(), Ok(()));
+
+        let err = Config {
+            islands: 2,
+            objectives: Objectives::FoodAndDistance,
+            ..Config::default()
+        }
+        .validate()
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            ConfigError::Incompatible("islands", "multiple objectives")
+        );
+        assert_eq!(
+            err.to_string(),
+            "islands doesn't support multiple objectives"
+        );
+    }
+
+    #[test]
+    fn scaling_delegates_to_the_genetic_algorithm() {
+        let fitness = [2.0, 9.0, 9.0, 10.0];
+
+        assert_eq!(Scaling::None.scale(&fitness), fitness);
+
+        assert_eq!(
+            Scaling::Linear { multiplier: 2.0 }.scale(&fitness),
+            ga::LinearScaling::new(2.0).scale(&fitness),
+        );
+
+        assert_eq!(
+            Scaling::SigmaTruncation { c: 1.0 }.scale(&fitness),
+            ga::SigmaTruncation::new(1.0).scale(&fitness),
+        );
+
+        assert_eq!(
+            Scaling::Windowing { offset: 0.5 }.scale(&fitness),
+            ga::Windowing::new(0.5).scale(&fitness),
+        );
+
+        assert_eq!(
+            Scaling::ExponentialRanking { base: 0.5 }.scale(&fitness),
+            ga::ExponentialRanking::new(0.5).scale(&fitness),
+        );
+    }
+
+    #[test]
+    fn crossover_delegates_to_the_genetic_algorithm() {
+        let parent_a: ga::Chromosome = (1..=20).map(|n| n as f32).collect();
+        let parent_b: ga::Chromosome = (1..=20).map(|n| -n as f32).collect();
+
+        let check = |crossover: &Crossover, expected: &dyn ga::CrossoverMethod| {
+            let config = Config {
+                crossover: *crossover,
+                ..Config::default()
+            };
+
+            let actual = config.crossover_operator().crossover(
+                &mut ChaCha8Rng::from_seed(Default::default()),
+                &parent_a,
+                &parent_b,
+            );
+
+            let expected = expected.crossover(
+                &mut ChaCha8Rng::from_seed(Default::default()),
+                &parent_a,
+                &parent_b,
+            );
+
+            assert_eq!(
+                actual.into_iter().collect::<Vec<_>>(),
+                expected.into_iter().collect::<Vec<_>>(),
+            );
+        };
+
+        check(&Crossover::Uniform, &ga::UniformCrossover);
+        check(&Crossover::SinglePoint, &ga::SinglePointCrossover);
+        check(&Crossover::KPoint { k: 3 }, &ga::KPointCrossover::new(3));
+        check(
+            &Crossover::Arithmetic { alpha: 0.3 },
+            &ga::ArithmeticCrossover::new(0.3),
+        );
+        check(
+            &Crossover::Blend { alpha: 0.5 },
+            &ga::BlendCrossover::new(0.5),
+        );
+        check(
+            &Crossover::SimulatedBinary { eta: 2.0 },
+            &ga::SimulatedBinaryCrossover::new(2.0),
+        );
+    }
+
+    #[test_case(BrainOutputs::Unsigned, false ; "unsigned outputs")]
+    #[test_case(BrainOutputs::Antagonistic, false ; "antagonistic outputs")]
+    #[test_case(BrainOutputs::Unsigned, true ; "memory")]
+    fn neuron_crossover_inherits_whole_neurons(outputs: BrainOutputs, memory: bool) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let eye = Eye::default();
+
+        let config = Config {
+            crossover: Crossover::Neuron,
+            brain_outputs: outputs,
+            brain_memory: memory,
+            ..Config::default()
+        };
+
+        let topology = config.brain_topology();
+        let parent_a = Brain::random(&mut rng, &eye, topology).as_chromosome();
+        let parent_b = Brain::random(&mut rng, &eye, topology).as_chromosome();
+
+        let child = config
+            .crossover_operator()
+            .crossover(&mut rng, &parent_a, &parent_b);
+
+        for neuron in Brain::neuron_genes(&eye, topology) {
+            let from_a = neuron.clone().all(|idx| child[idx] == parent_a[idx]);
+            let from_b = neuron.clone().all(|idx| child[idx] == parent_b[idx]);
+
+            assert!(from_a || from_b);
+        }
+    }
+
+    #[test]
+    fn mutation_delegates_to_the_genetic_algorithm() {
+        let check = |mutation: Mutation, expected: &dyn ga::MutationMethod| {
+            let mut actual: ga::Chromosome = (1..=20).map(|n| n as f32 / 20.0).collect();
+            let mut expected_child = actual.clone();
+
+            let config = Config {
+                mutation,
+                mutation_chance: 0.5,
+                mutation_coeff: 0.3,
+                ..Config::default()
+            };
+
+            config
+                .mutation_operator()
+                .mutate(&mut ChaCha8Rng::from_seed(Default::default()), &mut actual);
+
+            expected.mutate(
+                &mut ChaCha8Rng::from_seed(Default::default()),
+                &mut expected_child,
+            );
+
+            assert_eq!(
+                actual.into_iter().collect::<Vec<_>>(),
+                expected_child.into_iter().collect::<Vec<_>>(),
+            );
+        };
+
+        check(Mutation::Uniform, &ga::UniformMutation::new(0.5, 0.3));
+        check(Mutation::Gaussian, &ga::NormalMutation::new(0.5, 0.3));
+        check(
+            Mutation::Polynomial { eta: 20.0 },
+            &ga::PolynomialMutation::new(0.5, 20.0, -1.0..=1.0),
+        );
+        check(Mutation::Reset, &ga::ResetMutation::new(0.5, -1.0..=1.0));
+        check(
+            Mutation::SelfAdaptive,
+            &ga::SelfAdaptiveMutation::new(0.5, 0.3),
+        );
+    }
+
+    #[test]
+    fn scaled_mutation_operator_keeps_chance_within_bounds() {
+        let operator = Config {
+            mutation_chance: 0.4,
+            mutation_coeff: 0.3,
+            ..Config::default()
+        }
+        .mutation_operator();
+
+        let halved = operator.scaled(0.5);
+        assert_eq!(halved.chance, 0.2);
+        assert_eq!(halved.coeff, 0.15);
+
+        let tripled = operator.scaled(3.0);
+        assert_eq!(tripled.chance, 1.0);
+        assert_eq!(tripled.coeff, 0.3 * 3.0);
+    }
+
+    #[test]
+    fn self_adaptive_mutation_floors_a_zero_coeff() {
+        let mut actual: ga::Chromosome = (1..=20).map(|n| n as f32 / 20.0).collect();
+
+        Config {
+            mutation: Mutation::SelfAdaptive,
+            mutation_coeff: 0.0,
+            ..Config::default()
+        }
+        .mutation_operator()
+        .mutate(&mut ChaCha8Rng::from_seed(Default::default()), &mut actual);
+
+        assert!(actual
+            .step_sizes()
+            .iter()
+            .all(|&step_size| step_size >= ga::SelfAdaptiveMutation::MIN_STEP_SIZE));
+    }
+
+    #[test]
+    fn hybrid_fitness_mixes_normalized_satiation_and_novelty() {
+        let mode = FitnessMode::Hybrid {
+            k: 3,
+            threshold: 0.1,
+            novelty_weight: 0.25,
+        };
+
+        let fitness = mode.fitness(&[0.0, 5.0, 10.0], &[2.0, 1.0, 0.0]);
+
+        assert_eq!(fitness, vec![0.25, 0.5, 0.75]);
+    }
+
+    mod behaviour {
+        use super::*;
+
+        fn animal() -> Animal {
+            let mut rng = ChaCha8Rng::from_seed(Default::default());
+            let mut animal = Animal::random(&mut rng);
+
+            animal.position = na::Point2::new(0.9, 0.8);
+
+            animal.trajectory = vec![
+                na::Point2::new(0.1, 0.1),
+                na::Point2::new(0.2, 0.1),
+                na::Point2::new(0.6, 0.7),
+                na::Point2::new(0.9, 0.8),
+            ];
+
+            animal
+        }
+
+        #[test]
+        fn final_position() {
+            assert_eq!(Behaviour::FinalPosition.describe(&animal()), vec![0.9, 0.8]);
+        }
+
+        #[test]
+        fn visitation() {
+            assert_eq!(
+                Behaviour::Visitation { cells: 2 }.describe(&animal()),
+                vec![0.5, 0.0, 0.0, 0.5],
+            );
+        }
+
+        #[test]
+        fn trajectory() {
+            assert_eq!(
+                Behaviour::Trajectory { samples: 2 }.describe(&animal()),
+                vec![0.1, 0.1, 0.9, 0.8],
+            );
+        }
+
+        #[test]
+        fn trajectory_of_an_animal_that_has_not_moved_yet() {
+            let animal = Animal {
+                trajectory: Vec::new(),
+                ..animal()
+            };
+
+            assert_eq!(
+                Behaviour::Trajectory { samples: 2 }.describe(&animal),
+                vec![0.9, 0.8, 0.9, 0.8],
+            );
+        }
+    }
+}
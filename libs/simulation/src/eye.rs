@@ -94,7 +94,7 @@ const FOV_ANGLE: f32 = PI + FRAC_PI_4;
 /// than ~20 photoreceptors yielding progressively worse results.
 const CELLS: usize = 9;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Eye {
     fov_range: f32,
     fov_angle: f32,
@@ -1,15 +1,27 @@
-pub use self::{animal::*, animal_individual::*, brain::*, eye::*, food::*, world::*};
+pub use self::{
+    animal::*, animal_individual::*, brain::*, checkpoint::*, config::*, eye::*, food::*, meal::*,
+    replay::*, world::*,
+};
 use lib_genetic_algorithm as ga;
-pub use lib_genetic_algorithm::Statistics;
+pub use lib_genetic_algorithm::{
+    DifferentialEvolutionVariant, Elitism, HallOfFame, HallOfFameEntry, Migration,
+    MigrationTopology, ParetoFront, Replacement, Speciation, Statistics,
+};
 use lib_neural_network as nn;
 use nalgebra as na;
-use rand::{Rng, RngExt};
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
 
 mod animal;
 mod animal_individual;
 mod brain;
+mod checkpoint;
+mod config;
 mod eye;
 mod food;
+mod meal;
+mod replay;
 mod world;
 
 // FRAC_PI_2 = PI / 2.0; a convenient shortcut
@@ -61,52 +73,46 @@ const ROTATION_ACCEL: f32 = FRAC_PI_2;
 /// to live"; 2500 was chosen with a fair dice roll.
 const GENERATION_LENGTH: usize = 2500;
 
-/// Tunable knobs affecting how a [`Simulation`] is set up.
-///
-/// All fields have sane defaults (see [`Config::default`]) matching the
-/// values this simulation originally shipped with.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Config {
-    /// How many animals (birds) populate the world.
-    pub num_animals: usize,
-
-    /// How many foods populate the world.
-    pub num_foods: usize,
-
-    /// Probability of a single gene mutating during evolution.
-    ///
-    /// - 0.0 = no genes will be touched
-    /// - 1.0 = all genes will be touched
-    pub mutation_chance: f32,
+/// For how many steps birds must live before they can get replaced in
+/// steady-state evolution (see [`Evolution::SteadyState`]); without it
+/// newborns, which haven't had a chance to eat anything yet, would be the
+/// first ones to go.
+const MATURITY_AGE: usize = GENERATION_LENGTH / 5;
 
-    /// Magnitude of a mutation, when it happens.
-    ///
-    /// - 0.0 = touched genes will not be modified
-    /// - 1.0 = touched genes will be += or -= by at most 3.0
-    pub mutation_coeff: f32,
+/// Birds whose brains differ by less than this (see
+/// [`ga::compatibility_distance()`]) count as the same bird in the hall of
+/// fame.
+const HALL_OF_FAME_MIN_DISTANCE: f32 = 0.01;
 
-    /// Maximum speed a bird can reach.
-    pub max_speed: f32,
-}
+/// How often (in steps) birds' positions get recorded into their
+/// trajectories.
+const TRAJECTORY_INTERVAL: usize = 25;
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            num_animals: 40,
-            num_foods: 60,
-            mutation_chance: 0.01,
-            mutation_coeff: 0.3,
-            max_speed: SPEED_MAX,
-        }
-    }
-}
+/// How many behaviours the novelty archive remembers at most; the oldest
+/// ones get forgotten first.
+const NOVELTY_ARCHIVE_SIZE: usize = 1000;
 
 pub struct Simulation {
     world: World,
-    ga: ga::GeneticAlgorithm<ga::RouletteWheelSelection>,
+    ga: ga::IslandModel<ga::RouletteWheelSelection>,
+    nsga2: ga::Nsga2,
+    pareto_front: Option<ga::ParetoFront>,
+    novelty_archive: Option<ga::NoveltyArchive>,
+    cmaes: Option<ga::Cmaes>,
+    hall_of_fame: ga::HallOfFame,
     age: usize,
     generation: usize,
-    max_speed: f32,
+    config: Config,
+    mutation_schedule: MutationSchedule,
+
+    /// Factor mutation is currently scaled by (see [`Config::mutation_schedule`])
+    mutation_factor: f32,
+
+    /// Random number generator owned by the simulation; see [`Self::seeded()`]
+    rng: Option<ChaCha8Rng>,
+
+    /// Foods eaten during the last step
+    meals: Vec<Meal>,
 }
 
 impl Simulation {
@@ -114,24 +120,113 @@ impl Simulation {
         Self::random_with_config(rng, Config::default())
     }
 
+    /// Creates a simulation of given config.
+    ///
+    /// Panics if the config is invalid - see [`Config::validate()`].
     pub fn random_with_config(rng: &mut dyn Rng, config: Config) -> Self {
-        let world = World::random_with(rng, config.num_animals, config.num_foods);
+        if let Err(err) = config.validate() {
+            panic!("invalid config: {err}");
+        }
 
-        let ga = ga::GeneticAlgorithm::new(
-            ga::RouletteWheelSelection::new(),
-            ga::UniformCrossover,
-            ga::GaussianMutation::new(config.mutation_chance, config.mutation_coeff),
-        );
+        let mut world = World::random_with_config(rng, &config);
+
+        for (idx, animal) in world.animals.iter_mut().enumerate() {
+            animal.island = idx % config.islands;
+        }
+
+        Self::new(world, config)
+    }
+
+    /// Creates a simulation that owns its random number generator, seeded
+    /// with [`Config::seed`] - so that the run can be reproduced, step for
+    /// step, on any platform (including wasm).
+    ///
+    /// Such simulation gets driven by [`Self::step_seeded()`] and
+    /// [`Self::train_seeded()`].
+    ///
+    /// Panics if the config is invalid - see [`Config::validate()`].
+    pub fn seeded(config: Config) -> Self {
+        let seed = config.seed.unwrap_or_else(|| rand::rng().random());
+
+        let config = Config {
+            seed: Some(seed),
+            ..config
+        };
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut simulation = Self::random_with_config(&mut rng, config);
+
+        simulation.rng = Some(rng);
+        simulation
+    }
+
+    /// Creates a simulation of given world, with the optimizer (and
+    /// everything else) fresh.
+    fn new(world: World, config: Config) -> Self {
+        let cmaes = match config.optimizer {
+            Optimizer::GeneticAlgorithm => None,
+            Optimizer::Cmaes { sigma } => Some(ga::Cmaes::new(sigma)),
+        };
+
+        let islands = (0..config.islands)
+            .map(|_| {
+                let island = ga::GeneticAlgorithm::new(
+                    ga::RouletteWheelSelection::new(),
+                    config.crossover_operator(),
+                    config.mutation_operator(),
+                )
+                .with_elitism(config.elitism)
+                .with_fitness_scaling(config.fitness_scaling);
+
+                let island = match config.speciation {
+                    Some(speciation) => island.with_speciation(speciation),
+                    None => island,
+                };
+
+                match config.reproduction {
+                    Reproduction::Standard => island,
+                    Reproduction::DifferentialEvolution { variant, f, cr } => {
+                        island.with_reproduction(ga::DifferentialEvolution::new(variant, f, cr))
+                    }
+                }
+            })
+            .collect();
+
+        let ga = ga::IslandModel::new(islands).with_migration(config.migration);
+
+        let nsga2 = ga::Nsga2::new(config.crossover_operator(), config.mutation_operator())
+            .with_elitism(config.elitism);
 
         Self {
             world,
             ga,
+            nsga2,
+            pareto_front: None,
+            novelty_archive: config.fitness_mode.novelty_archive(),
+            cmaes,
+            hall_of_fame: ga::HallOfFame::new(config.hall_of_fame)
+                .with_min_distance(HALL_OF_FAME_MIN_DISTANCE),
             age: 0,
             generation: 0,
-            max_speed: config.max_speed,
+            config,
+            mutation_schedule: config.mutation_schedule,
+            mutation_factor: 1.0,
+            rng: None,
+            meals: Vec::new(),
         }
     }
 
+    /// Returns seed of the simulation's own random number generator; `None`
+    /// unless the simulation has been created with [`Self::seeded()`].
+    pub fn seed(&self) -> Option<u64> {
+        self.rng.as_ref().and(self.config.seed)
+    }
+
+    /// Returns foods eaten during the last step.
+    pub fn meals(&self) -> &[Meal] {
+        &self.meals
+    }
+
     pub fn world(&self) -> &World {
         &self.world
     }
@@ -141,6 +236,83 @@ impl Simulation {
         self.generation
     }
 
+    /// Returns birds that made the best trade-offs between objectives in
+    /// the last generation; available only when evolving for more than one
+    /// objective (see [`Config::objectives`]).
+    pub fn pareto_front(&self) -> Option<&ga::ParetoFront> {
+        self.pareto_front.as_ref()
+    }
+
+    /// Returns the best birds ever seen, judged by satiation.
+    pub fn hall_of_fame(&self) -> &ga::HallOfFame {
+        &self.hall_of_fame
+    }
+
+    /// Restarts the world with the all-time champion (see
+    /// [`Self::hall_of_fame()`]) - every bird gets its brain, so that
+    /// evolution can pick up from there after the population has drifted
+    /// away.
+    ///
+    /// The optimizer starts over, too (e.g. CMA-ES gets centered on the
+    /// champion, and mutation goes back to the start of its schedule) - only
+    /// the hall of fame and the generation counter are kept.
+    ///
+    /// Returns `false` (doing nothing) when there's no champion yet.
+    pub fn restart_with_champion(&mut self, rng: &mut dyn Rng) -> bool {
+        let Some(champion) = self.hall_of_fame.champion() else {
+            return false;
+        };
+
+        let champion = champion.chromosome.clone();
+
+        let mut world = World::random_with_config(rng, &self.config);
+
+        for (idx, animal) in world.animals.iter_mut().enumerate() {
+            *animal =
+                Animal::from_chromosome_with(champion.clone(), self.config.brain_topology(), rng);
+            animal.island = idx % self.config.islands;
+        }
+
+        *self = Self {
+            hall_of_fame: self.hall_of_fame.clone(),
+            generation: self.generation,
+            rng: self.rng.take(),
+            ..Self::new(world, self.config)
+        };
+
+        true
+    }
+
+    /// Same as [`Self::restart_with_champion()`], but using the simulation's
+    /// own random number generator.
+    pub fn restart_with_champion_seeded(&mut self) -> bool {
+        self.with_own_rng(Self::restart_with_champion)
+    }
+
+    /// Same as [`Self::step()`], but using the simulation's own random
+    /// number generator.
+    pub fn step_seeded(&mut self) -> Option<ga::Statistics> {
+        self.with_own_rng(Self::step)
+    }
+
+    /// Same as [`Self::train()`], but using the simulation's own random
+    /// number generator.
+    pub fn train_seeded(&mut self) -> ga::Statistics {
+        self.with_own_rng(Self::train)
+    }
+
+    fn with_own_rng<T>(&mut self, f: impl FnOnce(&mut Self, &mut dyn Rng) -> T) -> T {
+        let mut rng = self
+            .rng
+            .take()
+            .expect("simulation doesn't own a random number generator - see Simulation::seeded()");
+
+        let result = f(self, &mut rng);
+
+        self.rng = Some(rng);
+        result
+    }
+
     /// Performs a single step - a single second, so to say - of our simulation
     pub fn step(&mut self, rng: &mut dyn Rng) -> Option<ga::Statistics> {
         self.process_collisions(rng);
@@ -149,7 +321,22 @@ impl Simulation {
 
         self.age += 1;
 
-        if self.age > GENERATION_LENGTH {
+        if let Evolution::SteadyState {
+            interval,
+            count,
+            replacement,
+        } = self.config.evolution
+        {
+            if self.age.is_multiple_of(interval) {
+                self.replace(rng, count, replacement);
+            }
+
+            if self.age > GENERATION_LENGTH {
+                Some(self.complete_generation())
+            } else {
+                None
+            }
+        } else if self.age > GENERATION_LENGTH {
             Some(self.evolve(rng))
         } else {
             None
@@ -166,13 +353,20 @@ impl Simulation {
     }
 
     fn process_collisions(&mut self, rng: &mut dyn Rng) {
-        for animal in &mut self.world.animals {
-            for food in &mut self.world.foods {
+        self.meals.clear();
+
+        for (animal_idx, animal) in self.world.animals.iter_mut().enumerate() {
+            for (food_idx, food) in self.world.foods.iter_mut().enumerate() {
                 let distance = na::distance(&animal.position, &food.position);
 
                 if distance <= 0.01 {
                     animal.satiation += 1;
                     food.position = rng.random();
+
+                    self.meals.push(Meal {
+                        animal: animal_idx,
+                        food: food_idx,
+                    });
                 }
             }
         }
@@ -185,21 +379,34 @@ impl Simulation {
                     .eye
                     .process_vision(animal.position, animal.rotation, &self.world.foods);
 
-            let response = animal.brain.nn.propagate(vision);
+            let (speed, rotation) = animal.brain.response(vision, &mut animal.memory);
+
+            let speed = speed.clamp(-SPEED_ACCEL, SPEED_ACCEL);
 
-            let speed = response[0].clamp(-SPEED_ACCEL, SPEED_ACCEL);
+            let rotation = rotation.clamp(-ROTATION_ACCEL, ROTATION_ACCEL);
 
-            let rotation = response[1].clamp(-ROTATION_ACCEL, ROTATION_ACCEL);
+            animal.energy += speed.abs() + rotation.abs();
 
-            animal.speed = (animal.speed + speed).clamp(SPEED_MIN, self.max_speed);
+            animal.speed = (animal.speed + speed).clamp(SPEED_MIN, self.config.max_speed);
 
             animal.rotation = na::Rotation2::new(animal.rotation.angle() + rotation);
         }
     }
 
     fn process_movements(&mut self) {
+        // (trajectories are needed only for novelty search, which is not
+        // supported by steady-state evolution, where birds can live forever)
+        let record_trajectory = self.age.is_multiple_of(TRAJECTORY_INTERVAL)
+            && !self.config.evolution.is_steady_state();
+
         for animal in &mut self.world.animals {
             animal.position += animal.rotation * na::Vector2::new(animal.speed, 0.0);
+            animal.distance += animal.speed;
+            animal.age += 1;
+
+            if record_trajectory {
+                animal.trajectory.push(animal.position);
+            }
 
             animal.position.x = na::wrap(animal.position.x, 0.0, 1.0);
             animal.position.y = na::wrap(animal.position.y, 0.0, 1.0);
@@ -215,19 +422,92 @@ impl Simulation {
             .world
             .animals
             .iter()
-            .map(AnimalIndividual::from_animal)
+            .map(|animal| AnimalIndividual::from_animal_with(animal, self.config.objectives))
             .collect();
 
-        // Step 2: Evolve birdies
-        let (evoloved_population, stats) = self.ga.evolve(rng, &current_population);
+        let mut stats = ga::Statistics::new(&current_population);
 
-        // Step 3: Bring birdies back from the genetic algorithm
-        self.world.animals = evoloved_population
-            .into_iter()
-            .map(|individual| individual.into_animal(rng))
-            .collect();
+        self.hall_of_fame
+            .record(&current_population, self.generation - 1);
 
-        // Step 4: Restart foods
+        // Step 2: Reward novelty, if requested
+        //
+        // (statistics keep on describing satiation, so that it's possible
+        // to compare runs regardless of what birds are rewarded for)
+        let current_population = if let Some(novelty_archive) = &mut self.novelty_archive {
+            let behaviours: Vec<_> = self
+                .world
+                .animals
+                .iter()
+                .map(|animal| self.config.behaviour.describe(animal))
+                .collect();
+
+            let novelty = novelty_archive.evaluate(&behaviours);
+
+            let satiation: Vec<_> = current_population
+                .iter()
+                .map(ga::Individual::fitness)
+                .collect();
+
+            let fitness = self.config.fitness_mode.fitness(&satiation, &novelty);
+
+            current_population
+                .into_iter()
+                .zip(fitness)
+                .map(|(individual, fitness)| individual.with_fitness(fitness))
+                .collect()
+        } else {
+            current_population
+        };
+
+        // Step 3: Adjust mutation to the schedule
+        self.adjust_mutation(&stats);
+
+        // Step 4: Evolve birdies
+        let evolved_populations = if let Some(cmaes) = &mut self.cmaes {
+            vec![cmaes.evolve(rng, &current_population).0]
+        } else if self.config.objectives.is_multi_objective() {
+            let (evolved_population, _, pareto_front) = self.nsga2.evolve(rng, &current_population);
+
+            self.pareto_front = Some(pareto_front);
+
+            vec![evolved_population]
+        } else {
+            // ... each island on its own
+            let mut populations: Vec<Vec<_>> =
+                (0..self.config.islands).map(|_| Vec::new()).collect();
+
+            for (animal, individual) in self.world.animals.iter().zip(current_population) {
+                populations[animal.island].push(individual);
+            }
+
+            let (evolved_populations, islands_stats) =
+                self.ga.evolve(rng, self.generation, &populations);
+
+            stats = stats.with_species_sizes(
+                islands_stats
+                    .iter()
+                    .flat_map(|island_stats| island_stats.species_sizes())
+                    .copied()
+                    .collect(),
+            );
+
+            evolved_populations
+        };
+
+        // Step 5: Bring birdies back from the genetic algorithm
+        self.world.animals.clear();
+
+        for (island, population) in evolved_populations.into_iter().enumerate() {
+            for individual in population {
+                let mut animal = individual.into_animal_with(self.config.brain_topology(), rng);
+                animal.island = island;
+
+                self.world.animals.push(animal);
+            }
+        }
+
+        // Step 6: Restart foods
         //
         // (this is not strictly necessary, but it allows to easily spot
         // when the evolution happens - so it's more of a UI thing. )
@@ -236,6 +516,90 @@ impl Simulation {
         }
         stats
     }
+
+    /// Replaces `count` birds with offspring of the birds on their islands;
+    /// see [`Evolution::SteadyState`].
+    fn replace(&mut self, rng: &mut dyn Rng, count: usize, replacement: ga::Replacement) {
+        for _ in 0..count {
+            let mature: Vec<_> = (0..self.world.animals.len())
+                .filter(|&idx| self.world.animals[idx].age >= MATURITY_AGE)
+                .collect();
+
+            if mature.is_empty() {
+                return;
+            }
+
+            let candidates: Vec<_> = mature
+                .iter()
+                .map(|&idx| self.steady_state_individual(&self.world.animals[idx]))
+                .collect();
+
+            let victim = mature[replacement.victim(rng, &candidates)];
+            let island = self.world.animals[victim].island;
+
+            let parents: Vec<_> = mature
+                .iter()
+                .zip(candidates)
+                .filter(|(idx, _)| self.world.animals[**idx].island == island)
+                .map(|(_, individual)| individual)
+                .collect();
+
+            let child = self.ga.islands()[island].offspring(rng, &parents);
+
+            let mut animal = child.into_animal_with(self.config.brain_topology(), rng);
+            animal.island = island;
+
+            self.world.animals[victim] = animal;
+        }
+    }
+
+    /// Completes a generation of steady-state evolution - which, since
+    /// birds get replaced as the simulation goes, boils down to reporting
+    /// statistics and adjusting mutation.
+    fn complete_generation(&mut self) -> ga::Statistics {
+        self.age = 0;
+        self.generation += 1;
+
+        let population: Vec<_> = self
+            .world
+            .animals
+            .iter()
+            .map(|animal| self.steady_state_individual(animal))
+            .collect();
+
+        let stats = ga::Statistics::new(&population);
+
+        self.hall_of_fame.record(&population, self.generation - 1);
+        self.adjust_mutation(&stats);
+
+        stats
+    }
+
+    /// Returns an individual for given bird, judged by how much it eats per
+    /// generation's worth of steps - so that birds of different ages can be
+    /// compared.
+    fn steady_state_individual(&self, animal: &Animal) -> AnimalIndividual {
+        let fitness = animal.satiation as f32 * GENERATION_LENGTH as f32 / animal.age.max(1) as f32;
+
+        AnimalIndividual::from_animal(animal).with_fitness(fitness)
+    }
+
+    fn adjust_mutation(&mut self, stats: &ga::Statistics) {
+        self.mutation_factor =
+            ga::MutationSchedule::factor(&mut self.mutation_schedule, self.generation, stats);
+
+        self.apply_mutation_factor();
+    }
+
+    fn apply_mutation_factor(&mut self) {
+        let mutation_operator = self.config.mutation_operator().scaled(self.mutation_factor);
+
+        for island in self.ga.islands_mut() {
+            island.set_mutation_method(mutation_operator);
+        }
+
+        self.nsga2.set_mutation_method(mutation_operator);
+    }
 }
 
 #[cfg(test)]
@@ -243,6 +607,7 @@ mod tests {
     use super::*;
     use rand::SeedableRng;
     use rand_chacha::ChaCha8Rng;
+    use test_case::test_case;
 
     #[test]
     fn random_creates_a_world_with_forty_animals_and_sixty_foods() {
@@ -307,7 +672,19 @@ mod tests {
         assert_eq!(config.num_foods, 60);
         assert_eq!(config.mutation_chance, 0.01);
         assert_eq!(config.mutation_coeff, 0.3);
+        assert_eq!(config.mutation, Mutation::Uniform);
         assert_eq!(config.max_speed, SPEED_MAX);
+        assert_eq!(config.elitism, ga::Elitism::Count(0));
+        assert_eq!(config.mutation_schedule, MutationSchedule::Constant);
+        assert_eq!(config.islands, 1);
+        assert_eq!(config.speciation, None);
+        assert_eq!(config.fitness_mode, FitnessMode::Satiation);
+        assert_eq!(config.optimizer, Optimizer::GeneticAlgorithm);
+        assert_eq!(config.reproduction, Reproduction::Standard);
+        assert_eq!(config.fitness_scaling, Scaling::None);
+        assert_eq!(config.crossover, Crossover::Uniform);
+        assert_eq!(config.evolution, Evolution::Generational);
+        assert_eq!(config.hall_of_fame, 10);
     }
 
     #[test]
@@ -343,4 +720,818 @@ mod tests {
             assert!(animal.speed <= config.max_speed);
         }
     }
+
+    #[test]
+    fn random_with_config_honors_the_requested_elitism() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let config = Config {
+            elitism: ga::Elitism::Count(1),
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        simulation.world.animals[7].satiation = 100;
+        let champion = simulation.world.animals[7].as_chromosome();
+
+        simulation.evolve(&mut rng);
+
+        assert_eq!(
+            simulation.world().animals()[0]
+                .as_chromosome()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            champion.into_iter().collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn evolve_copes_with_birds_that_have_not_eaten_anything() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        assert!(simulation
+            .world()
+            .animals()
+            .iter()
+            .all(|animal| animal.satiation == 0));
+
+        let stats = simulation.evolve(&mut rng);
+
+        assert_eq!(stats.max_fitness(), 0.0);
+        assert_eq!(simulation.generation(), 1);
+        assert_eq!(simulation.world().animals().len(), 40);
+    }
+
+    #[test]
+    fn self_adaptive_mutation_gives_birds_their_own_step_sizes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            mutation: Mutation::SelfAdaptive,
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        assert!(simulation
+            .world()
+            .animals()
+            .iter()
+            .all(|animal| animal.mutation_step_sizes.is_empty()));
+
+        simulation.evolve(&mut rng);
+
+        for animal in simulation.world().animals() {
+            assert_eq!(
+                animal.mutation_step_sizes.len(),
+                animal.as_chromosome().len()
+            );
+        }
+    }
+
+    #[test_case(MutationSchedule::LinearDecay(ga::LinearDecay::new(1.0, 0.0, 2)) ; "linear decay")]
+    #[test_case(MutationSchedule::ExponentialDecay(ga::ExponentialDecay::new(1e-30, 0.0)) ; "exponential decay")]
+    fn self_adaptive_mutation_survives_a_schedule_decaying_to_zero(schedule: MutationSchedule) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            num_animals: 12,
+            num_foods: 12,
+            mutation: Mutation::SelfAdaptive,
+            mutation_schedule: schedule,
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        for _ in 0..4 {
+            simulation.evolve(&mut rng);
+        }
+
+        assert_eq!(simulation.mutation_factor, 0.0);
+        assert_eq!(simulation.world().animals().len(), 12);
+    }
+
+    #[test]
+    fn mutation_schedule_follows_the_generations() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            mutation_schedule: MutationSchedule::OneFifthRule(ga::OneFifthRule::new(1, 0.5)),
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        // Nobody eats anything, so there's no improvement whatsoever
+        simulation.evolve(&mut rng);
+        simulation.evolve(&mut rng);
+
+        assert_ne!(simulation.mutation_schedule, config.mutation_schedule);
+        assert_eq!(simulation.config, config);
+    }
+
+    #[test]
+    fn islands_keep_their_birds_apart() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            num_animals: 10,
+            islands: 3,
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        let island_sizes = |simulation: &Simulation| {
+            (0..3)
+                .map(|island| {
+                    simulation
+                        .world()
+                        .animals()
+                        .iter()
+                        .filter(|animal| animal.island == island)
+                        .count()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(island_sizes(&simulation), vec![4, 3, 3]);
+
+        simulation.evolve(&mut rng);
+
+        assert_eq!(island_sizes(&simulation), vec![4, 3, 3]);
+        assert_eq!(simulation.world().animals().len(), 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn more_islands_than_birds() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                num_animals: 2,
+                islands: 3,
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    fn multi_objective_evolution_reports_the_pareto_front() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            objectives: Objectives::FoodAndDistance,
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        assert!(simulation.pareto_front().is_none());
+
+        simulation.world.animals[3].satiation = 5;
+        simulation.world.animals[3].distance = 2.0;
+        simulation.world.animals[8].satiation = 2;
+        simulation.world.animals[8].distance = 1.0;
+
+        for (idx, animal) in simulation.world.animals.iter_mut().enumerate() {
+            if idx != 3 && idx != 8 {
+                animal.distance = 3.0;
+            }
+        }
+
+        simulation.evolve(&mut rng);
+
+        let pareto_front = simulation.pareto_front().unwrap();
+
+        assert_eq!(pareto_front.indices(), &[3, 8]);
+        assert_eq!(
+            pareto_front.objectives(),
+            &[vec![5.0, -2.0], vec![2.0, -1.0]]
+        );
+        assert_eq!(simulation.world().animals().len(), 40);
+    }
+
+    #[test]
+    fn birds_keep_track_of_distance_and_energy() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        for _ in 0..10 {
+            simulation.step(&mut rng);
+        }
+
+        for animal in simulation.world().animals() {
+            assert!(animal.distance > 0.0);
+            assert!(animal.energy >= 0.0);
+        }
+    }
+
+    #[test]
+    fn speciation_reports_species_across_islands() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            islands: 2,
+            speciation: Some(ga::Speciation::new(0.0)),
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+        let stats = simulation.evolve(&mut rng);
+
+        // With zero threshold, each (random) bird is a species of its own
+        assert_eq!(stats.species_count(), 40);
+
+        let config = Config {
+            speciation: None,
+            ..config
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+        let stats = simulation.evolve(&mut rng);
+
+        assert_eq!(stats.species_sizes(), &[20, 20]);
+    }
+
+    #[test]
+    fn novelty_search_archives_behaviours() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            fitness_mode: FitnessMode::Novelty {
+                k: 5,
+                threshold: 0.0,
+            },
+            behaviour: Behaviour::Visitation { cells: 4 },
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        simulation.train(&mut rng);
+
+        let novelty_archive = simulation.novelty_archive.as_ref().unwrap();
+
+        assert!(!novelty_archive.is_empty());
+        assert!(novelty_archive
+            .behaviours()
+            .iter()
+            .all(|behaviour| behaviour.len() == 16));
+
+        // Trajectories start anew with each generation
+        assert!(simulation
+            .world()
+            .animals()
+            .iter()
+            .all(|animal| animal.trajectory.is_empty()));
+    }
+
+    #[test]
+    fn cmaes_can_be_swapped_in_for_the_genetic_algorithm() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            optimizer: Optimizer::Cmaes { sigma: 0.3 },
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        simulation.world.animals[7].satiation = 5;
+        simulation.evolve(&mut rng);
+
+        let cmaes = simulation.cmaes.as_ref().unwrap();
+
+        assert!(cmaes.mean().is_some());
+        assert_eq!(simulation.generation(), 1);
+        assert_eq!(simulation.world().animals().len(), 40);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cmaes_does_not_support_islands() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                islands: 2,
+                optimizer: Optimizer::Cmaes { sigma: 0.3 },
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test_case(Config {
+        elitism: ga::Elitism::Count(2),
+        ..Config::default()
+    } ; "elitism")]
+    #[test_case(Config {
+        speciation: Some(ga::Speciation::new(0.5)),
+        ..Config::default()
+    } ; "speciation")]
+    #[test_case(Config {
+        fitness_scaling: Scaling::Linear { multiplier: 2.0 },
+        ..Config::default()
+    } ; "fitness scaling")]
+    #[test_case(Config {
+        reproduction: Reproduction::DifferentialEvolution {
+            variant: ga::DifferentialEvolutionVariant::Rand1Bin,
+            f: 0.5,
+            cr: 0.9,
+        },
+        ..Config::default()
+    } ; "differential evolution")]
+    #[test_case(Config {
+        crossover: Crossover::Neuron,
+        ..Config::default()
+    } ; "crossover")]
+    #[test_case(Config {
+        mutation: Mutation::Gaussian,
+        ..Config::default()
+    } ; "mutation")]
+    #[test_case(Config {
+        mutation_schedule: MutationSchedule::LinearDecay(ga::LinearDecay::new(1.0, 0.1, 10)),
+        ..Config::default()
+    } ; "mutation schedule")]
+    #[should_panic(expected = "CMA-ES doesn't support")]
+    fn cmaes_does_not_support_the_genetic_algorithm_knobs(config: Config) {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                optimizer: Optimizer::Cmaes { sigma: 0.3 },
+                ..config
+            },
+        );
+    }
+
+    #[test]
+    fn differential_evolution_can_be_swapped_in_for_the_standard_reproduction() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            reproduction: Reproduction::DifferentialEvolution {
+                variant: ga::DifferentialEvolutionVariant::CurrentToBest1,
+                f: 0.5,
+                cr: 0.9,
+            },
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        simulation.world.animals[7].satiation = 5;
+        simulation.evolve(&mut rng);
+        simulation.evolve(&mut rng);
+
+        assert_eq!(simulation.generation(), 2);
+        assert_eq!(simulation.world().animals().len(), 40);
+    }
+
+    #[test]
+    #[should_panic]
+    fn differential_evolution_does_not_support_elitism() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                reproduction: Reproduction::DifferentialEvolution {
+                    variant: ga::DifferentialEvolutionVariant::Rand1Bin,
+                    f: 0.5,
+                    cr: 0.9,
+                },
+                elitism: ga::Elitism::Count(2),
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn differential_evolution_does_not_support_elitism_fraction() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                reproduction: Reproduction::DifferentialEvolution {
+                    variant: ga::DifferentialEvolutionVariant::Rand1Bin,
+                    f: 0.5,
+                    cr: 0.9,
+                },
+                elitism: ga::Elitism::Fraction(0.1),
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn differential_evolution_needs_at_least_four_birds() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                reproduction: Reproduction::DifferentialEvolution {
+                    variant: ga::DifferentialEvolutionVariant::Rand1Bin,
+                    f: 0.5,
+                    cr: 0.9,
+                },
+                num_animals: 3,
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn differential_evolution_does_not_support_islands() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                reproduction: Reproduction::DifferentialEvolution {
+                    variant: ga::DifferentialEvolutionVariant::Rand1Bin,
+                    f: 0.5,
+                    cr: 0.9,
+                },
+                islands: 2,
+                migration: ga::Migration::new(ga::MigrationTopology::Ring, 1, 1),
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    fn steady_state_evolution_replaces_birds_a_few_at_a_time() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            evolution: Evolution::SteadyState {
+                interval: 100,
+                count: 1,
+                replacement: ga::Replacement::WorstOut,
+            },
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+        let stats = simulation.train(&mut rng);
+
+        assert_eq!(simulation.generation(), 1);
+        assert_eq!(stats.species_sizes(), &[40]);
+        assert_eq!(simulation.world().animals().len(), 40);
+
+        let ages: Vec<_> = simulation
+            .world()
+            .animals()
+            .iter()
+            .map(|animal| animal.age)
+            .collect();
+
+        // One bird gets replaced every 100 steps since step 500, when the
+        // first ones matured (some offspring can get replaced again)...
+        let newborns = ages.iter().filter(|&&age| age < GENERATION_LENGTH).count();
+
+        assert!(newborns > 0 && newborns <= 21);
+
+        // ... while the rest keeps on living, into the next generation
+        simulation.step(&mut rng);
+
+        assert!(simulation
+            .world()
+            .animals()
+            .iter()
+            .any(|animal| animal.age > GENERATION_LENGTH));
+    }
+
+    #[test]
+    #[should_panic]
+    fn steady_state_evolution_does_not_support_cmaes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                optimizer: Optimizer::Cmaes { sigma: 0.3 },
+                evolution: Evolution::SteadyState {
+                    interval: 100,
+                    count: 1,
+                    replacement: ga::Replacement::OldestOut,
+                },
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn steady_state_evolution_does_not_support_speciation() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                evolution: Evolution::SteadyState {
+                    interval: 100,
+                    count: 1,
+                    replacement: ga::Replacement::OldestOut,
+                },
+                speciation: Some(ga::Speciation::new(0.5)),
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn steady_state_evolution_does_not_support_elitism() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                evolution: Evolution::SteadyState {
+                    interval: 100,
+                    count: 1,
+                    replacement: ga::Replacement::OldestOut,
+                },
+                elitism: ga::Elitism::Count(2),
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn steady_state_evolution_does_not_support_migration() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random_with_config(
+            &mut rng,
+            Config {
+                evolution: Evolution::SteadyState {
+                    interval: 100,
+                    count: 1,
+                    replacement: ga::Replacement::OldestOut,
+                },
+                islands: 2,
+                migration: ga::Migration::new(ga::MigrationTopology::Ring, 1, 1),
+                ..Config::default()
+            },
+        );
+    }
+
+    #[test]
+    fn hall_of_fame_remembers_the_champion() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        assert!(!simulation.restart_with_champion(&mut rng));
+
+        simulation.world.animals[3].satiation = 100;
+        let champion: Vec<_> = simulation.world.animals[3]
+            .as_chromosome()
+            .iter()
+            .copied()
+            .collect();
+
+        simulation.evolve(&mut rng);
+        simulation.evolve(&mut rng);
+
+        let entry = simulation.hall_of_fame().champion().unwrap();
+
+        assert!(entry.chromosome.iter().eq(&champion));
+        assert_eq!(entry.fitness, 100.0);
+        assert_eq!(entry.generation, 0);
+        assert_eq!(simulation.hall_of_fame().len(), 10);
+
+        assert!(simulation.restart_with_champion(&mut rng));
+        assert_eq!(simulation.world().animals().len(), 40);
+
+        assert!(simulation
+            .world()
+            .animals()
+            .iter()
+            .all(|animal| animal.as_chromosome().iter().eq(&champion)));
+    }
+
+    #[test]
+    fn restarting_with_champion_starts_the_optimizer_over() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let config = Config {
+            num_animals: 12,
+            num_foods: 12,
+            optimizer: Optimizer::Cmaes { sigma: 0.3 },
+            ..Config::default()
+        };
+
+        let mut simulation = Simulation::random_with_config(&mut rng, config);
+
+        simulation.world.animals[3].satiation = 100;
+        let champion = simulation.world.animals[3].as_chromosome();
+
+        simulation.evolve(&mut rng);
+        simulation.evolve(&mut rng);
+
+        assert!(simulation.cmaes.as_ref().unwrap().mean().is_some());
+        assert!(simulation.restart_with_champion(&mut rng));
+        assert!(simulation.cmaes.as_ref().unwrap().mean().is_none());
+        assert_eq!(simulation.generation(), 2);
+        assert_eq!(simulation.hall_of_fame().len(), 10);
+
+        simulation.evolve(&mut rng);
+
+        let mean = simulation.cmaes.as_ref().unwrap().mean().unwrap();
+
+        assert_eq!(mean.len(), champion.len());
+
+        assert!(mean
+            .iter()
+            .zip(champion.iter())
+            .all(|(actual, expected)| (actual - expected).abs() < 1e-5));
+    }
+
+    #[test]
+    fn seeded_simulations_are_reproducible() {
+        let config = Config {
+            num_animals: 12,
+            num_foods: 12,
+            seed: Some(1234),
+            ..Config::default()
+        };
+
+        let mut a = Simulation::seeded(config);
+        let mut b = Simulation::seeded(config);
+
+        assert_eq!(a.seed(), Some(1234));
+
+        for _ in 0..=GENERATION_LENGTH + 100 {
+            assert_eq!(
+                a.step_seeded().map(|stats| stats.avg_fitness()),
+                b.step_seeded().map(|stats| stats.avg_fitness()),
+            );
+        }
+
+        assert_eq!(a.generation(), 1);
+
+        for (a, b) in a.world().animals().iter().zip(b.world().animals()) {
+            assert_eq!(a.position, b.position);
+            assert_eq!(a.rotation, b.rotation);
+            assert_eq!(a.speed, b.speed);
+        }
+    }
+
+    /// Steps a simulation for a while, returning every change of birds'
+    /// speed and rotation along the way.
+    fn brain_responses(brain_outputs: BrainOutputs) -> Vec<(f32, f32)> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut simulation = Simulation::random_with_config(
+            &mut rng,
+            Config {
+                brain_outputs,
+                ..Config::default()
+            },
+        );
+
+        let mut responses = Vec::new();
+
+        for _ in 0..20 {
+            let before: Vec<_> = simulation
+                .world()
+                .animals()
+                .iter()
+                .map(|animal| (animal.speed, animal.rotation))
+                .collect();
+
+            simulation.step(&mut rng);
+
+            for (animal, (speed, rotation)) in simulation.world().animals().iter().zip(before) {
+                responses.push((
+                    animal.speed - speed,
+                    rotation.rotation_to(&animal.rotation).angle(),
+                ));
+            }
+        }
+
+        responses
+    }
+
+    #[test]
+    fn with_unsigned_brain_outputs_birds_never_slow_down_nor_turn_clockwise() {
+        let responses = brain_responses(BrainOutputs::Unsigned);
+
+        assert!(responses.iter().all(|&(speed, _)| speed >= 0.0));
+        assert!(responses.iter().all(|&(_, rotation)| rotation >= -1e-6));
+    }
+
+    #[test_case(BrainOutputs::Bipolar ; "bipolar")]
+    #[test_case(BrainOutputs::Antagonistic ; "antagonistic")]
+    fn with_signed_brain_outputs_birds_slow_down_and_turn_both_ways(brain_outputs: BrainOutputs) {
+        let responses = brain_responses(brain_outputs);
+
+        assert!(responses.iter().any(|&(speed, _)| speed < 0.0));
+        assert!(responses.iter().any(|&(_, rotation)| rotation < -1e-6));
+        assert!(responses.iter().any(|&(_, rotation)| rotation > 1e-6));
+    }
+
+    #[test]
+    fn birds_with_memory_forget_everything_on_evolution() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut simulation = Simulation::random_with_config(
+            &mut rng,
+            Config {
+                num_animals: 12,
+                num_foods: 12,
+                brain_memory: true,
+                ..Config::default()
+            },
+        );
+
+        let remembers = |simulation: &Simulation| {
+            simulation.world().animals().iter().any(|animal| {
+                animal
+                    .memory
+                    .layers()
+                    .iter()
+                    .flatten()
+                    .any(|&value| value != 0.0)
+            })
+        };
+
+        assert!(!remembers(&simulation));
+
+        for _ in 0..10 {
+            simulation.step(&mut rng);
+        }
+
+        assert!(remembers(&simulation));
+
+        simulation.train(&mut rng);
+
+        assert!(!remembers(&simulation));
+    }
+
+    #[test]
+    fn seeded_simulations_follow_the_seed() {
+        // Pins the exact trajectory of a seeded run, so that it can be
+        // compared between platforms (e.g. native vs wasm) and releases
+        let mut simulation = Simulation::seeded(Config {
+            seed: Some(42),
+            ..Config::default()
+        });
+
+        for _ in 0..100 {
+            simulation.step_seeded();
+        }
+
+        let animal = &simulation.world().animals()[0];
+
+        assert_eq!(animal.position, na::Point2::new(0.4825131, 0.082920216));
+        assert_eq!(animal.rotation.angle(), -0.109726734);
+        assert_eq!(animal.speed, 0.005);
+    }
+
+    #[test]
+    fn seeded_picks_a_random_seed_when_none_is_given() {
+        let simulation = Simulation::seeded(Config::default());
+
+        assert!(simulation.seed().is_some());
+    }
+
+    #[test]
+    fn unseeded_simulations_have_no_seed() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let simulation = Simulation::random_with_config(
+            &mut rng,
+            Config {
+                seed: Some(1234),
+                ..Config::default()
+            },
+        );
+
+        assert_eq!(simulation.seed(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unseeded_simulations_cannot_step_on_their_own() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        Simulation::random(&mut rng).step_seeded();
+    }
 }
@@ -0,0 +1,11 @@
+use crate::*;
+
+/// Food eaten by an animal; see [`Simulation::meals()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Meal {
+    /// Index of the animal (within [`World::animals()`])
+    pub animal: usize,
+
+    /// Index of the food (within [`World::foods()`])
+    pub food: usize,
+}
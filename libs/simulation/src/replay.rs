@@ -0,0 +1,195 @@
+pub use self::{frame::*, player::*, recorder::*};
+
+use crate::*;
+use std::{error, fmt, io};
+
+mod frame;
+mod player;
+mod recorder;
+
+/// Bytes every replay starts with.
+const MAGIC: [u8; 4] = *b"SHRP";
+
+/// Version of the replay format; bump whenever anything saved by
+/// [`Replay::save()`] changes its shape.
+pub const REPLAY_VERSION: u32 = 4;
+
+/// Recording of a simulation, step by step - see [`Recorder`] and
+/// [`Player`].
+///
+/// Replays don't contain brains, so they can't be resumed - but the config
+/// and the seed are there, so (for seeded simulations) the run can be
+/// reproduced from scratch.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    config: Config,
+    seed: Option<u64>,
+    frames: Vec<Frame>,
+}
+
+impl Replay {
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Returns seed of the recorded simulation; `None` unless the
+    /// simulation has been created with [`Simulation::seeded()`].
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Returns all the frames; the first one shows the simulation as it was
+    /// when the recording started, and each next one - after another step.
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn save(&self, mut writer: impl io::Write) -> Result<(), ReplayError> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&REPLAY_VERSION.to_le_bytes())?;
+
+        bincode::serialize_into(&mut writer, self)?;
+
+        Ok(())
+    }
+
+    pub fn load(mut reader: impl io::Read) -> Result<Self, ReplayError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+
+        if magic != MAGIC {
+            return Err(ReplayError::NotAReplay);
+        }
+
+        let mut version = [0; 4];
+        reader.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+
+        if version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+
+        Ok(bincode::deserialize_from(&mut reader)?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+
+    /// Replay is malformed
+    Format(bincode::Error),
+
+    /// Data doesn't look like a replay at all
+    NotAReplay,
+
+    /// Replay has been saved in a different version of the format
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "couldn't access replay: {err}"),
+            Self::Format(err) => write!(f, "malformed replay: {err}"),
+            Self::NotAReplay => write!(f, "not a replay"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported replay version: {version} (expected {REPLAY_VERSION})"
+            ),
+        }
+    }
+}
+
+impl error::Error for ReplayError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Format(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReplayError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<bincode::Error> for ReplayError {
+    fn from(err: bincode::Error) -> Self {
+        Self::Format(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records a seeded simulation for given number of steps.
+    pub(super) fn record(steps: usize) -> (Simulation, Replay) {
+        let mut simulation = Simulation::seeded(Config {
+            num_animals: 12,
+            num_foods: 12,
+            seed: Some(1234),
+            ..Config::default()
+        });
+
+        let mut recorder = Recorder::new(&simulation);
+
+        for _ in 0..steps {
+            simulation.step_seeded();
+            recorder.record(&simulation);
+        }
+
+        (simulation, recorder.finish())
+    }
+
+    #[test]
+    fn save_and_load() {
+        let (_, replay) = record(10);
+
+        let mut bytes = Vec::new();
+        replay.save(&mut bytes).unwrap();
+
+        assert_eq!(Replay::load(bytes.as_slice()).unwrap(), replay);
+    }
+
+    #[test]
+    fn is_compact() {
+        let (_, replay) = record(10);
+
+        let mut bytes = Vec::new();
+        replay.save(&mut bytes).unwrap();
+
+        // 11 frames x (12 animals x 10 bytes + 12 foods x 4 bytes), plus
+        // change
+        assert!(bytes.len() < 2500, "got {} bytes", bytes.len());
+    }
+
+    #[test]
+    fn rejects_other_data() {
+        let err = Replay::load(b"hello, world".as_slice()).err().unwrap();
+
+        assert!(matches!(err, ReplayError::NotAReplay));
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend((REPLAY_VERSION + 1).to_le_bytes());
+
+        let err = Replay::load(bytes.as_slice()).err().unwrap();
+
+        assert!(matches!(err, ReplayError::UnsupportedVersion(v) if v == REPLAY_VERSION + 1));
+    }
+}
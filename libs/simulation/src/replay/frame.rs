@@ -0,0 +1,148 @@
+use crate::*;
+use std::f32::consts::PI;
+
+/// State of the simulation after a single step, as recorded by
+/// [`Recorder`].
+///
+/// Positions and rotations are stored with 16 bits of precision (that's
+/// about 1/65536th of the world), which is plenty for viewing and keeps
+/// replays compact.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Frame {
+    generation: usize,
+    age: usize,
+    animals: Vec<AnimalFrame>,
+    foods: Vec<FoodFrame>,
+    meals: Vec<Meal>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnimalFrame {
+    x: u16,
+    y: u16,
+    rotation: u16,
+    speed: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FoodFrame {
+    x: u16,
+    y: u16,
+}
+
+impl Frame {
+    pub(super) fn new(simulation: &Simulation) -> Self {
+        let world = simulation.world();
+
+        Self {
+            generation: simulation.generation,
+            age: simulation.age,
+            animals: world.animals().iter().map(AnimalFrame::new).collect(),
+            foods: world.foods().iter().map(FoodFrame::new).collect(),
+            meals: simulation.meals().to_vec(),
+        }
+    }
+
+    /// Returns how many generations had been completed by this frame.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Returns how many steps into the generation this frame is.
+    pub fn age(&self) -> usize {
+        self.age
+    }
+
+    pub fn animals(&self) -> &[AnimalFrame] {
+        &self.animals
+    }
+
+    pub fn foods(&self) -> &[FoodFrame] {
+        &self.foods
+    }
+
+    /// Returns foods eaten during the step that led to this frame (see
+    /// [`Simulation::meals()`]).
+    pub fn meals(&self) -> &[Meal] {
+        &self.meals
+    }
+}
+
+impl AnimalFrame {
+    fn new(animal: &Animal) -> Self {
+        Self {
+            x: quantize(animal.position.x),
+            y: quantize(animal.position.y),
+            rotation: quantize((animal.rotation.angle() + PI) / (2.0 * PI)),
+            speed: animal.speed,
+        }
+    }
+
+    pub fn position(&self) -> na::Point2<f32> {
+        na::Point2::new(dequantize(self.x), dequantize(self.y))
+    }
+
+    pub fn rotation(&self) -> na::Rotation2<f32> {
+        na::Rotation2::new(dequantize(self.rotation) * 2.0 * PI - PI)
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+}
+
+impl FoodFrame {
+    fn new(food: &Food) -> Self {
+        Self {
+            x: quantize(food.position.x),
+            y: quantize(food.position.y),
+        }
+    }
+
+    pub fn position(&self) -> na::Point2<f32> {
+        na::Point2::new(dequantize(self.x), dequantize(self.y))
+    }
+}
+
+/// Maps `0.0..=1.0` into `0..=u16::MAX`.
+fn quantize(value: f32) -> u16 {
+    (value.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16
+}
+
+fn dequantize(value: u16) -> f32 {
+    value as f32 / u16::MAX as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn keeps_positions_rotations_and_speeds() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        for _ in 0..100 {
+            let mut animal = Animal::random(&mut rng);
+            animal.speed = 0.0042;
+
+            let frame = AnimalFrame::new(&animal);
+
+            assert!(na::distance(&frame.position(), &animal.position) < 1e-4);
+            assert!(frame.rotation().angle_to(&animal.rotation).abs() < 1e-3);
+            assert_eq!(frame.speed(), 0.0042);
+
+            let food = Food::random(&mut rng);
+
+            assert!(na::distance(&FoodFrame::new(&food).position(), &food.position) < 1e-4);
+        }
+    }
+
+    #[test]
+    fn quantize_covers_the_entire_range() {
+        assert_eq!(quantize(0.0), 0);
+        assert_eq!(quantize(1.0), u16::MAX);
+        assert_eq!(dequantize(quantize(0.5)), 32768.0 / 65535.0);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::*;
+
+/// Plays a [`Replay`] back - frame by frame, or jumping straight to any
+/// step or generation (without re-running the simulation).
+#[derive(Clone, Debug)]
+pub struct Player {
+    replay: Replay,
+
+    /// Index of the frame each generation starts at
+    generations: Vec<usize>,
+
+    position: usize,
+}
+
+impl Player {
+    pub fn new(replay: Replay) -> Self {
+        assert!(!replay.is_empty());
+
+        let generations = (0..replay.len())
+            .filter(|&idx| {
+                idx == 0 || replay.frames[idx].generation() != replay.frames[idx - 1].generation()
+            })
+            .collect();
+
+        Self {
+            replay,
+            generations,
+            position: 0,
+        }
+    }
+
+    pub fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    /// Returns index of the current frame.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn frame(&self) -> &Frame {
+        &self.replay.frames[self.position]
+    }
+
+    /// Jumps to given frame (counting from the start of the recording);
+    /// positions past the end land at the last frame.
+    pub fn seek(&mut self, position: usize) -> &Frame {
+        self.position = position.min(self.replay.len() - 1);
+        self.frame()
+    }
+
+    /// Jumps to the first frame of given generation; returns `None` (staying
+    /// in place) if that generation hasn't been recorded.
+    pub fn seek_generation(&mut self, generation: usize) -> Option<&Frame> {
+        let position = self
+            .generations
+            .iter()
+            .copied()
+            .find(|&idx| self.replay.frames[idx].generation() == generation)?;
+
+        Some(self.seek(position))
+    }
+
+    /// Moves to the next frame; returns `None` at the end of the replay.
+    pub fn advance(&mut self) -> Option<&Frame> {
+        if self.position + 1 < self.replay.len() {
+            self.position += 1;
+            Some(self.frame())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::record;
+    use super::*;
+
+    #[test]
+    fn plays_frame_by_frame() {
+        let (_, replay) = record(3);
+        let mut player = Player::new(replay.clone());
+
+        assert_eq!(player.frame(), &replay.frames()[0]);
+        assert_eq!(player.advance(), Some(&replay.frames()[1]));
+        assert_eq!(player.advance(), Some(&replay.frames()[2]));
+        assert_eq!(player.advance(), Some(&replay.frames()[3]));
+        assert_eq!(player.advance(), None);
+        assert_eq!(player.position(), 3);
+    }
+
+    #[test]
+    fn seeks() {
+        let (_, replay) = record(10);
+        let mut player = Player::new(replay.clone());
+
+        assert_eq!(player.seek(7), &replay.frames()[7]);
+        assert_eq!(player.seek(2), &replay.frames()[2]);
+        assert_eq!(player.seek(100), &replay.frames()[10]);
+    }
+
+    #[test]
+    fn seeks_generations() {
+        let (_, replay) = record(GENERATION_LENGTH + 10);
+        let mut player = Player::new(replay);
+
+        let frame = player.seek_generation(1).unwrap();
+
+        assert_eq!((frame.generation(), frame.age()), (1, 0));
+        assert_eq!(player.position(), GENERATION_LENGTH + 1);
+
+        assert!(player.seek_generation(2).is_none());
+        assert_eq!(player.position(), GENERATION_LENGTH + 1);
+
+        let frame = player.seek_generation(0).unwrap();
+
+        assert_eq!((frame.generation(), frame.age()), (0, 0));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::*;
+
+/// Records a simulation into a [`Replay`], step by step.
+///
+/// # Example
+///
+/// ```
+/// # use lib_simulation::*;
+/// let mut simulation = Simulation::seeded(Config::default());
+/// let mut recorder = Recorder::new(&simulation);
+///
+/// for _ in 0..100 {
+///     simulation.step_seeded();
+///     recorder.record(&simulation);
+/// }
+///
+/// let replay = recorder.finish();
+///
+/// assert_eq!(replay.len(), 101);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    replay: Replay,
+}
+
+impl Recorder {
+    /// Starts recording; the simulation's current state becomes the first
+    /// frame.
+    pub fn new(simulation: &Simulation) -> Self {
+        Self {
+            replay: Replay {
+                config: simulation.config,
+                seed: simulation.seed(),
+                frames: vec![Frame::new(simulation)],
+            },
+        }
+    }
+
+    /// Records the simulation's state after a step; should be called after
+    /// each [`Simulation::step()`] (or [`Simulation::step_seeded()`]).
+    pub fn record(&mut self, simulation: &Simulation) {
+        self.replay.frames.push(Frame::new(simulation));
+    }
+
+    pub fn finish(self) -> Replay {
+        self.replay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::record;
+    use super::*;
+
+    #[test]
+    fn records_every_step() {
+        let (simulation, replay) = record(GENERATION_LENGTH + 10);
+
+        assert_eq!(replay.len(), GENERATION_LENGTH + 11);
+        assert_eq!(replay.seed(), Some(1234));
+        assert_eq!(replay.config().num_animals, 12);
+
+        let first = &replay.frames()[0];
+
+        assert_eq!((first.generation(), first.age()), (0, 0));
+
+        let last = replay.frames().last().unwrap();
+
+        assert_eq!((last.generation(), last.age()), (1, 9));
+
+        for (frame, animal) in last.animals().iter().zip(simulation.world().animals()) {
+            assert!(na::distance(&frame.position(), &animal.position) < 1e-4);
+        }
+    }
+
+    #[test]
+    fn records_meals() {
+        let (simulation, replay) = record(500);
+
+        let meals: usize = replay
+            .frames()
+            .iter()
+            .map(|frame| frame.meals().len())
+            .sum();
+
+        let satiation: usize = simulation
+            .world()
+            .animals()
+            .iter()
+            .map(|animal| animal.satiation)
+            .sum();
+
+        assert!(meals > 0);
+        assert_eq!(meals, satiation);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::*;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct World {
     pub animals: Vec<Animal>,
     pub foods: Vec<Food>,
@@ -12,9 +12,22 @@ impl World {
     }
 
     pub fn random_with(rng: &mut dyn Rng, num_animals: usize, num_foods: usize) -> Self {
-        let animals = (0..num_animals).map(|_| Animal::random(rng)).collect();
+        Self::random_with_config(
+            rng,
+            &Config {
+                num_animals,
+                num_foods,
+                ..Config::default()
+            },
+        )
+    }
+
+    pub fn random_with_config(rng: &mut dyn Rng, config: &Config) -> Self {
+        let animals = (0..config.num_animals)
+            .map(|_| Animal::random_with(rng, config.brain_topology()))
+            .collect();
 
-        let foods = (0..num_foods).map(|_| Food::random(rng)).collect();
+        let foods = (0..config.num_foods).map(|_| Food::random(rng)).collect();
 
         Self { animals, foods }
     }
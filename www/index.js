@@ -15,17 +15,32 @@ const chartCtxt = chart.getContext('2d');
 const pauseBtn = document.getElementById('pause');
 const resetBtn = document.getElementById('reset');
 const trainBtn = document.getElementById('train');
+const championBtn = document.getElementById('champion');
+const recordBtn = document.getElementById('record');
 
+const replayFile = document.getElementById('replay-file');
+const replayPanel = document.getElementById('replay');
+const replaySeek = document.getElementById('replay-seek');
+const replayPosition = document.getElementById('replay-position');
+const replayCloseBtn = document.getElementById('replay-close');
+
+const statSeed = document.getElementById('stat-seed');
 const statGeneration = document.getElementById('stat-generation');
 const statMin = document.getElementById('stat-min');
 const statMax = document.getElementById('stat-max');
 const statAvg = document.getElementById('stat-avg');
+const statMedian = document.getElementById('stat-median');
+const statStdDev = document.getElementById('stat-std-dev');
+const statDiversity = document.getElementById('stat-diversity');
+const statSpecies = document.getElementById('stat-species');
 
 const cfgNumAnimals = document.getElementById('cfg-num-animals');
 const cfgNumFoods = document.getElementById('cfg-num-foods');
 const cfgMutationChance = document.getElementById('cfg-mutation-chance');
 const cfgMutationCoeff = document.getElementById('cfg-mutation-coeff');
 const cfgMaxSpeed = document.getElementById('cfg-max-speed');
+const cfgEliteCount = document.getElementById('cfg-elite-count');
+const cfgSeed = document.getElementById('cfg-seed');
 const configApplyBtn = document.getElementById('config-apply');
 
 const statAnimals = document.getElementById('stat-animals');
@@ -40,6 +55,10 @@ let trailsEnabled = true;
 let isLightTheme = false;
 let simSpeed = 1;
 
+// Replay being played back, if any; while it's loaded, the viewport shows
+// the replay instead of the live simulation.
+let player = null;
+
 // History of per-generation fitness stats, used to draw the chart.
 // Capped so the chart stays readable and memory bounded over long runs.
 const MAX_HISTORY = 200;
@@ -55,6 +74,10 @@ function recordStats(stats) {
     statMin.textContent = stats.min_fitness.toFixed(2);
     statMax.textContent = stats.max_fitness.toFixed(2);
     statAvg.textContent = stats.avg_fitness.toFixed(2);
+    statMedian.textContent = stats.median_fitness.toFixed(2);
+    statStdDev.textContent = stats.std_dev_fitness.toFixed(2);
+    statDiversity.textContent = stats.gene_diversity.toFixed(4);
+    statSpecies.textContent = stats.species_count;
 
     statsHistory.push(stats);
 
@@ -66,9 +89,14 @@ function recordStats(stats) {
 }
 
 function resetStats() {
+    statSeed.textContent = simulation.seed();
     statMin.textContent = '-';
     statMax.textContent = '-';
     statAvg.textContent = '-';
+    statMedian.textContent = '-';
+    statStdDev.textContent = '-';
+    statDiversity.textContent = '-';
+    statSpecies.textContent = '-';
     statsHistory = [];
     drawChart();
 }
@@ -93,14 +121,43 @@ function drawChart() {
         return chartHeight - t * chartHeight;
     }
 
-    function drawLine(color, valueOf) {
+    // Diversity is on a completely different scale than fitness, so it gets
+    // its own one (spanning the entire height of the chart)
+    const hiDiversity = Math.max(...statsHistory.map((s) => s.gene_diversity), 0.000001);
+
+    function toDiversityY(diversity) {
+        return chartHeight - (diversity / hiDiversity) * chartHeight;
+    }
+
+    // Interquartile range, drawn as a band behind the lines
+    chartCtxt.beginPath();
+    chartCtxt.fillStyle = 'rgba(255, 220, 99, 0.15)';
+
+    statsHistory.forEach((s, i) => {
+        const x = i * xStep;
+
+        if (i === 0) {
+            chartCtxt.moveTo(x, toY(s.q3_fitness));
+        } else {
+            chartCtxt.lineTo(x, toY(s.q3_fitness));
+        }
+    });
+
+    for (let i = statsHistory.length - 1; i >= 0; i -= 1) {
+        chartCtxt.lineTo(i * xStep, toY(statsHistory[i].q1_fitness));
+    }
+
+    chartCtxt.closePath();
+    chartCtxt.fill();
+
+    function drawLine(color, valueOf, toChartY = toY) {
         chartCtxt.beginPath();
         chartCtxt.strokeStyle = color;
         chartCtxt.lineWidth = 1.5;
 
         statsHistory.forEach((s, i) => {
             const x = i * xStep;
-            const y = toY(valueOf(s));
+            const y = toChartY(valueOf(s));
 
             if (i === 0) {
                 chartCtxt.moveTo(x, y);
@@ -115,6 +172,8 @@ function drawChart() {
     drawLine('rgb(255, 99, 99)', (s) => s.max_fitness);
     drawLine('rgb(255, 220, 99)', (s) => s.avg_fitness);
     drawLine('rgb(99, 170, 255)', (s) => s.min_fitness);
+    drawLine('rgb(255, 160, 60)', (s) => s.median_fitness);
+    drawLine('rgb(140, 220, 140)', (s) => s.gene_diversity, toDiversityY);
 }
 
 trainBtn.onclick = function () {
@@ -123,13 +182,76 @@ trainBtn.onclick = function () {
     recordStats(stats);
 };
 
+championBtn.onclick = function () {
+    simulation.restart_with_champion();
+};
+
 pauseBtn.onclick = function () {
     isPaused = !isPaused;
     pauseBtn.textContent = isPaused ? 'resume' : 'pause';
 };
 
+recordBtn.onclick = function () {
+    if (!simulation.is_recording()) {
+        simulation.start_recording();
+        recordBtn.textContent = 'stop recording';
+        return;
+    }
+
+    const replay = simulation.stop_recording();
+    recordBtn.textContent = 'record';
+
+    const link = document.createElement('a');
+    link.href = URL.createObjectURL(new Blob([replay]));
+    link.download = `shorelark-${simulation.seed()}.replay`;
+    link.click();
+    URL.revokeObjectURL(link.href);
+};
+
+function showReplayPosition() {
+    replaySeek.value = player.position();
+    replayPosition.textContent =
+        `frame ${player.position() + 1} / ${player.len()}, generation ${player.generation()}`;
+}
+
+replayFile.onchange = async function () {
+    const file = replayFile.files[0];
+
+    if (!file) {
+        return;
+    }
+
+    try {
+        player = new sim.Player(new Uint8Array(await file.arrayBuffer()));
+    } catch (err) {
+        alert(`couldn't load replay: ${err}`);
+        return;
+    } finally {
+        replayFile.value = '';
+    }
+
+    replaySeek.max = player.len() - 1;
+    replayPanel.classList.add('visible');
+    showReplayPosition();
+    ctxt.clearRect(0, 0, viewportWidth, viewportHeight);
+};
+
+replaySeek.oninput = function () {
+    player.seek(parseInt(replaySeek.value, 10));
+    showReplayPosition();
+    ctxt.clearRect(0, 0, viewportWidth, viewportHeight);
+};
+
+replayCloseBtn.onclick = function () {
+    player.free();
+    player = null;
+    replayPanel.classList.remove('visible');
+    ctxt.clearRect(0, 0, viewportWidth, viewportHeight);
+};
+
 resetBtn.onclick = function () {
     simulation = new sim.Simulation();
+    recordBtn.textContent = 'record';
     resetStats();
     ctxt.clearRect(0, 0, viewportWidth, viewportHeight);
 };
@@ -141,11 +263,21 @@ function readConfigFromInputs() {
         parseFloat(cfgMutationChance.value),
         parseFloat(cfgMutationCoeff.value),
         parseFloat(cfgMaxSpeed.value),
+        parseInt(cfgEliteCount.value, 10),
+        // Seeds are 64-bit, so they travel as BigInts; empty = random seed
+        cfgSeed.value === '' ? undefined : BigInt(cfgSeed.value),
     ];
 }
 
 configApplyBtn.onclick = function () {
-    simulation = new sim.Simulation(...readConfigFromInputs());
+    try {
+        simulation = new sim.Simulation(...readConfigFromInputs());
+    } catch (err) {
+        alert(`couldn't apply config: ${err}`);
+        return;
+    }
+
+    recordBtn.textContent = 'record';
     resetStats();
     ctxt.clearRect(0, 0, viewportWidth, viewportHeight);
 };
@@ -235,26 +367,37 @@ function redraw() {
             ctxt.clearRect(0, 0, viewportWidth, viewportHeight);
         }
 
-        const stats = simulation.step();
+        let world;
 
-        if (stats) {
-            console.log(stats);
-        }
+        if (player) {
+            for (let i = 0; i < simSpeed; i++) {
+                player.advance();
+            }
+
+            showReplayPosition();
+            world = player.world();
+        } else {
+            const stats = simulation.step();
+
+            if (stats) {
+                console.log(stats);
+            }
 
-        recordStats(stats);
+            recordStats(stats);
 
-        // Fast-forward: run the remaining steps for this frame without
-        // re-rendering in between, so higher speeds actually save wall
-        // time instead of just rendering faster.
-        for (let i = 1; i < simSpeed; i++) {
-            const extraStats = simulation.step();
+            // Fast-forward: run the remaining steps for this frame without
+            // re-rendering in between, so higher speeds actually save wall
+            // time instead of just rendering faster.
+            for (let i = 1; i < simSpeed; i++) {
+                const extraStats = simulation.step();
 
-            if (extraStats) {
-                recordStats(extraStats);
+                if (extraStats) {
+                    recordStats(extraStats);
+                }
             }
-        }
 
-        const world = simulation.world();
+            world = simulation.world();
+        }
 
         statAnimals.textContent = world.animals.length;
         statFoods.textContent = world.foods.length;
@@ -285,4 +428,6 @@ function redraw() {
     requestAnimationFrame(redraw);
 }
 
+statSeed.textContent = simulation.seed();
+
 redraw();